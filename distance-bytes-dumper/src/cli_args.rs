@@ -2,12 +2,17 @@ use clap::Parser;
 
 #[derive(Parser, Debug)]
 pub struct Opt {
+    /// Format of the object read from stdin
+    #[clap(long, value_enum, case_insensitive = true, default_value = "bytes")]
+    pub input_format: Format,
+
+    /// Format of the object written to stdout
     #[clap(long, value_enum, case_insensitive = true)]
-    pub format: OutputFormat,
+    pub format: Format,
 }
 
 #[derive(clap::ValueEnum, Debug, Clone)]
-pub enum OutputFormat {
+pub enum Format {
     Json,
     Yaml,
     Bytes,
@@ -1,6 +1,6 @@
 mod cli_args;
 
-use crate::cli_args::OutputFormat;
+use crate::cli_args::Format;
 use anyhow::Error;
 use distance_bytes::GameObject;
 use std::io;
@@ -17,17 +17,22 @@ fn main() -> Result<(), Error> {
     let mut game_object = {
         let mut buf = Vec::new();
         io::stdin().read_to_end(&mut buf)?;
-        GameObject::read_from_reader(Cursor::new(buf))?
+
+        match args.input_format {
+            Format::Json => serde_json::from_slice(&buf)?,
+            Format::Yaml => serde_yaml::from_slice(&buf)?,
+            Format::Bytes => GameObject::read_from_reader(Cursor::new(buf))?,
+        }
     };
 
     match args.format {
-        OutputFormat::Json => {
+        Format::Json => {
             serde_json::to_writer(io::stdout(), &game_object)?;
         }
-        OutputFormat::Yaml => {
+        Format::Yaml => {
             serde_yaml::to_writer(io::stdout(), &game_object)?;
         }
-        OutputFormat::Bytes => {
+        Format::Bytes => {
             let mut buf = Cursor::new(Vec::new());
             game_object.write_to_writer(&mut buf)?;
             io::stdout().write_all(&buf.into_inner())?;
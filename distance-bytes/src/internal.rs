@@ -1,9 +1,9 @@
 pub use component::ComponentId;
 
-use anyhow::Result;
+use anyhow::{format_err, Result};
 use auto_impl::auto_impl;
 use chrono::TimeZone;
-use component::Component;
+use component::{Component, ComponentData, Transform, WorldTransform};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -14,14 +14,19 @@ use std::hash::Hash;
 use std::io::{BufReader, BufWriter, Read, Seek, Write};
 use std::path::Path;
 
+pub(crate) mod abilities;
 pub(crate) mod animator_base;
+pub(crate) mod binding_set;
 pub(crate) mod car_colors;
 pub(crate) mod car_data;
 pub(crate) mod component;
 pub(crate) mod deserializer;
 pub(crate) mod level_info;
+pub(crate) mod particle_report;
+pub(crate) mod player_profile;
 pub(crate) mod player_stats;
 pub(crate) mod serializer;
+pub(crate) mod spawn_points;
 
 mod string;
 mod util;
@@ -96,6 +101,89 @@ impl GameObject {
         let mut file = BufWriter::new(File::create(path.as_ref())?);
         serializer::write_game_object(&mut file, self)
     }
+
+    /// Returns the first component of this object with the given ID.
+    pub fn component(&self, id: ComponentId) -> Option<&Component> {
+        self.components
+            .iter()
+            .find(|component| component.id() == id)
+    }
+
+    /// Returns this object's `Transform` component, if it has one.
+    pub fn transform(&self) -> Option<&Transform> {
+        self.components
+            .iter()
+            .find_map(|component| match &component.data {
+                ComponentData::Transform(transform) => Some(transform),
+                _ => None,
+            })
+    }
+
+    /// Returns the objects parented to this one.
+    pub fn children(&self) -> &[GameObject] {
+        self.transform()
+            .map(|transform| transform.children.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Returns an iterator over this object and every object below it in the hierarchy, in
+    /// depth-first order.
+    pub fn descendants(&self) -> Descendants<'_> {
+        Descendants { stack: vec![self] }
+    }
+
+    /// Returns an iterator over this object and every object below it in the hierarchy, in
+    /// depth-first order, paired with their world transforms. This object is treated as the
+    /// root of the level, so its own `Transform` is taken as already being in world space.
+    pub fn world_transforms(&self) -> WorldTransforms<'_> {
+        WorldTransforms {
+            stack: vec![(self, WorldTransform::IDENTITY)],
+        }
+    }
+}
+
+/// Iterator returned by [`GameObject::descendants`].
+#[derive(Debug, Clone)]
+pub struct Descendants<'a> {
+    stack: Vec<&'a GameObject>,
+}
+
+impl<'a> Iterator for Descendants<'a> {
+    type Item = &'a GameObject;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let game_object = self.stack.pop()?;
+        self.stack.extend(game_object.children().iter().rev());
+
+        Some(game_object)
+    }
+}
+
+/// Iterator returned by [`GameObject::world_transforms`].
+#[derive(Debug, Clone)]
+pub struct WorldTransforms<'a> {
+    stack: Vec<(&'a GameObject, WorldTransform)>,
+}
+
+impl<'a> Iterator for WorldTransforms<'a> {
+    type Item = (&'a GameObject, WorldTransform);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (game_object, parent_world_transform) = self.stack.pop()?;
+        let world_transform = match game_object.transform() {
+            Some(transform) => parent_world_transform.then(transform),
+            None => parent_world_transform,
+        };
+        self.stack.extend(
+            game_object
+                .children()
+                .iter()
+                .rev()
+                .map(|child| (child, world_transform)),
+        );
+
+        Some((game_object, world_transform))
+    }
 }
 
 #[derive(Debug, Clone, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
@@ -122,6 +210,57 @@ impl Color {
     pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
         Color { r, g, b, a }
     }
+
+    /// Formats the color as `#rrggbb`, or `#rrggbbaa` if it isn't fully opaque. Fails if any
+    /// channel is outside the `0.0..=1.0` range, as HDR colors can't be written as hex.
+    pub fn to_hex(self) -> Result<String> {
+        let to_byte = |channel: f32| -> Result<u8> {
+            if (0.0..=1.0).contains(&channel) {
+                Ok((channel * 255.0).round() as u8)
+            } else {
+                Err(format_err!(
+                    "color channel {} is out of the 0-1 range",
+                    channel
+                ))
+            }
+        };
+
+        let mut hex = format!(
+            "#{:02x}{:02x}{:02x}",
+            to_byte(self.r)?,
+            to_byte(self.g)?,
+            to_byte(self.b)?
+        );
+        let a = to_byte(self.a)?;
+        if a != 255 {
+            hex.push_str(&format!("{:02x}", a));
+        }
+
+        Ok(hex)
+    }
+
+    /// Parses a color written as `rrggbb` or `rrggbbaa`, with or without a leading `#`.
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if !(digits.len() == 6 || digits.len() == 8) || !digits.is_ascii() {
+            return Err(format_err!("invalid hex color {:?}", hex));
+        }
+
+        let channel = |i: usize| -> Result<f32> {
+            digits
+                .get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .map(|byte| f32::from(byte) / 255.0)
+                .ok_or_else(|| format_err!("invalid hex color {:?}", hex))
+        };
+
+        Ok(Color {
+            r: channel(0)?,
+            g: channel(2)?,
+            b: channel(4)?,
+            a: if digits.len() == 8 { channel(6)? } else { 1.0 },
+        })
+    }
 }
 
 #[derive(
@@ -260,6 +399,47 @@ impl Default for LevelType {
     }
 }
 
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Hash,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    IntoPrimitive,
+    TryFromPrimitive,
+)]
+#[repr(i32)]
+pub enum GameModeId {
+    None = 0,
+    Sprint = 1,
+    Stunt = 2,
+    Soccer = 3,
+    FreeRoam = 4,
+    ReverseTag = 5,
+    LevelEditorPlay = 6,
+    CoopSprint = 7,
+    Challenge = 8,
+    Adventure = 9,
+    SpeedAndStyle = 10,
+    Trackmogrify = 11,
+    Demo = 12,
+    MainMenu = 13,
+    LostToEchoes = 14,
+    Nexus = 15,
+    TheOtherSide = 16,
+}
+
+impl Default for GameModeId {
+    fn default() -> Self {
+        GameModeId::None
+    }
+}
+
 #[allow(non_camel_case_types)]
 #[derive(
     Debug,
@@ -0,0 +1,208 @@
+use crate::internal::{Serializable, Visitor};
+use crate::{ComponentData, GameObject, LevelInfo};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// The set of car abilities that can be toggled on or off by a level.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct Abilities {
+    pub flying: bool,
+    pub jumping: bool,
+    pub boosting: bool,
+    pub jet_rotating: bool,
+}
+
+impl Abilities {
+    pub const ALL: Abilities = Abilities {
+        flying: true,
+        jumping: true,
+        boosting: true,
+        jet_rotating: true,
+    };
+
+    pub const NONE: Abilities = Abilities {
+        flying: false,
+        jumping: false,
+        boosting: false,
+        jet_rotating: false,
+    };
+
+    /// Returns the abilities enabled in either `self` or `other`.
+    pub fn union(self, other: Abilities) -> Abilities {
+        Abilities {
+            flying: self.flying || other.flying,
+            jumping: self.jumping || other.jumping,
+            boosting: self.boosting || other.boosting,
+            jet_rotating: self.jet_rotating || other.jet_rotating,
+        }
+    }
+
+    /// Returns the abilities enabled in both `self` and `other`.
+    pub fn intersection(self, other: Abilities) -> Abilities {
+        Abilities {
+            flying: self.flying && other.flying,
+            jumping: self.jumping && other.jumping,
+            boosting: self.boosting && other.boosting,
+            jet_rotating: self.jet_rotating && other.jet_rotating,
+        }
+    }
+
+    /// Returns the abilities a car has when it spawns in `level`. Adventure levels set these
+    /// with an `AdventureAbilitySettings` component; other levels use the abilities
+    /// `level_info` doesn't disable.
+    pub fn at_level_start(level_info: &LevelInfo, level: &GameObject) -> Abilities {
+        level
+            .descendants()
+            .flat_map(|game_object| &game_object.components)
+            .find_map(|component| match &component.data {
+                ComponentData::AdventureAbilitySettings(settings) => Some(settings.abilities),
+                _ => None,
+            })
+            .unwrap_or_else(|| Abilities::from(level_info))
+    }
+
+    /// Returns the abilities a car starting with `self` has after passing through `triggers`,
+    /// in the order given. Components other than `EnableAbilitiesTrigger` and
+    /// `SetAbilitiesTrigger` are ignored.
+    ///
+    /// Use [`at_level_start`](Abilities::at_level_start) as the starting point to get the
+    /// effective abilities at a point in a level.
+    pub fn after_triggers<'a>(
+        self,
+        triggers: impl IntoIterator<Item = &'a ComponentData>,
+    ) -> Abilities {
+        triggers
+            .into_iter()
+            .fold(self, |current, component_data| match component_data {
+                ComponentData::EnableAbilitiesTrigger(trigger) => trigger.apply(current),
+                ComponentData::SetAbilitiesTrigger(trigger) => trigger.apply(),
+                _ => current,
+            })
+    }
+}
+
+impl Default for Abilities {
+    fn default() -> Self {
+        Abilities::ALL
+    }
+}
+
+impl From<&LevelInfo> for Abilities {
+    fn from(level_info: &LevelInfo) -> Self {
+        Abilities {
+            flying: !level_info.disable_flying,
+            jumping: !level_info.disable_jumping,
+            boosting: !level_info.disable_boosting,
+            jet_rotating: !level_info.disable_jet_rotating,
+        }
+    }
+}
+
+impl Serializable for Abilities {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        visitor.visit_bool("enableFlying_", &mut self.flying)?;
+        visitor.visit_bool("enableJumping_", &mut self.jumping)?;
+        visitor.visit_bool("enableBoosting_", &mut self.boosting)?;
+        visitor.visit_bool("enableJetRotating_", &mut self.jet_rotating)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::{AdventureAbilitySettings, EnableAbilitiesTrigger, SetAbilitiesTrigger};
+    use crate::internal::test_util;
+
+    #[test]
+    fn test_at_level_start() {
+        let level_info = LevelInfo {
+            disable_flying: true,
+            ..Default::default()
+        };
+        let level = test_util::game_object("Level", vec![]);
+        assert_eq!(
+            Abilities::at_level_start(&level_info, &level),
+            Abilities {
+                flying: false,
+                ..Abilities::ALL
+            }
+        );
+
+        let settings = AdventureAbilitySettings {
+            abilities: Abilities {
+                jumping: true,
+                ..Abilities::NONE
+            },
+            ..Default::default()
+        };
+        let level = test_util::game_object(
+            "Level",
+            vec![ComponentData::AdventureAbilitySettings(settings)],
+        );
+        assert_eq!(
+            Abilities::at_level_start(&level_info, &level),
+            settings.abilities
+        );
+    }
+
+    #[test]
+    fn test_after_triggers() {
+        let level_info = LevelInfo {
+            disable_flying: true,
+            disable_boosting: true,
+            ..Default::default()
+        };
+        let start = Abilities::from(&level_info);
+        assert_eq!(
+            start,
+            Abilities {
+                flying: false,
+                jumping: true,
+                boosting: false,
+                jet_rotating: true,
+            }
+        );
+
+        let enable_flying = ComponentData::EnableAbilitiesTrigger(EnableAbilitiesTrigger {
+            abilities: Abilities {
+                flying: true,
+                ..Abilities::NONE
+            },
+            ..Default::default()
+        });
+        let set_jumping_only = ComponentData::SetAbilitiesTrigger(SetAbilitiesTrigger {
+            abilities: Abilities {
+                jumping: true,
+                ..Abilities::NONE
+            },
+            ..Default::default()
+        });
+
+        assert_eq!(
+            start.after_triggers(std::slice::from_ref(&enable_flying)),
+            Abilities {
+                boosting: false,
+                ..Abilities::ALL
+            }
+        );
+        assert_eq!(
+            start.after_triggers(&[enable_flying.clone(), set_jumping_only.clone()]),
+            Abilities {
+                jumping: true,
+                ..Abilities::NONE
+            }
+        );
+        assert_eq!(
+            start.after_triggers(&[set_jumping_only, enable_flying]),
+            Abilities {
+                flying: true,
+                jumping: true,
+                ..Abilities::NONE
+            }
+        );
+    }
+}
@@ -26,7 +26,49 @@ pub struct AnimatorBase {
     pub off_reset: bool,
 }
 
+/// A component whose motion is driven by an [`AnimatorBase`].
+pub trait Animator {
+    fn animator_base(&self) -> &AnimatorBase;
+    fn animator_base_mut(&mut self) -> &mut AnimatorBase;
+}
+
 impl AnimatorBase {
+    /// Seconds from the animation being started until it reaches its end position.
+    pub fn forward_time(&self) -> f32 {
+        self.delay + self.duration
+    }
+
+    /// Seconds from the animation reaching its end position until it has returned to its start.
+    /// Without custom pong values the return trip reuses the forward delay and duration.
+    pub fn pong_time(&self) -> f32 {
+        if self.custom_pong_values {
+            self.pong_delay + self.pong_duration
+        } else {
+            self.forward_time()
+        }
+    }
+
+    /// Seconds after which a looping animation repeats itself when driven by its default action,
+    /// or `None` if it doesn't repeat: when it doesn't loop, when it extends past its end
+    /// instead, or when the default action doesn't play it at all.
+    ///
+    /// Triggers with on/off actions can override the default action at runtime, which isn't
+    /// accounted for here. `time_offset` only shifts where in the cycle the animation starts,
+    /// so it doesn't affect the cycle's length.
+    pub fn cycle_time(&self) -> Option<f32> {
+        if !self.loop_ || self.extend {
+            return None;
+        }
+
+        match self.default_action.variant()? {
+            AnimatorBaseTriggerAction::PingPong => Some(self.forward_time() + self.pong_time()),
+            AnimatorBaseTriggerAction::Play | AnimatorBaseTriggerAction::PlayReverse => {
+                Some(self.forward_time())
+            }
+            AnimatorBaseTriggerAction::None | AnimatorBaseTriggerAction::Stop => None,
+        }
+    }
+
     pub(crate) fn visit_curve<V: Visitor>(&mut self, mut visitor: V) -> Result<()> {
         visitor.visit_f32("delay_", &mut self.delay)?;
         visitor.visit_f32("duration_", &mut self.duration)?;
@@ -90,6 +132,41 @@ impl AnimatorBase {
         Ok(extrapolation_type.variant() == Some(AnimatorBaseExtrapolationTypeObsolete::PingPong))
     }
 
+    /// Visits the delay, duration and curve fields written by the older trigger interpolators,
+    /// which predate the full curve and trigger blocks, and converts them to the equivalent
+    /// one-way animation.
+    pub(crate) fn visit_curve_simple<V: Visitor>(
+        &mut self,
+        mut visitor: V,
+        has_one_shot: bool,
+    ) -> Result<()> {
+        visitor.visit_f32("delay_", &mut self.delay)?;
+        visitor.visit_f32("duration_", &mut self.duration)?;
+        visitor.visit_enum("curveType_", &mut self.curve_type)?;
+
+        let mut one_shot = self.off_action == AnimatorBaseTriggerAction::None.into();
+        if has_one_shot {
+            visitor.visit_bool("oneShot_", &mut one_shot)?;
+        }
+
+        if V::VISIT_DIRECTION == VisitDirection::In {
+            self.loop_ = false;
+            self.custom_pong_values = false;
+            self.pong_delay = self.delay;
+            self.pong_duration = self.duration;
+            self.pong_curve_type = self.curve_type.map(AnimatorBaseCurveType::opposite);
+            self.default_action = AnimatorBaseTriggerAction::None.into();
+            self.on_action = AnimatorBaseTriggerAction::Play.into();
+            self.off_action = match one_shot {
+                true => AnimatorBaseTriggerAction::None,
+                false => AnimatorBaseTriggerAction::PlayReverse,
+            }
+            .into();
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn visit_trigger<V: Visitor>(&mut self, mut visitor: V) -> Result<()> {
         visitor.visit_enum("defaultAction_", &mut self.default_action)?;
 
@@ -260,3 +337,54 @@ impl Default for AnimatorBaseExtrapolationTypeObsolete {
         AnimatorBaseExtrapolationTypeObsolete::Normal
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn animator_base() -> AnimatorBase {
+        AnimatorBase {
+            delay: 0.5,
+            duration: 2.0,
+            pong_delay: 1.0,
+            pong_duration: 3.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_forward_and_pong_time() {
+        let mut animator_base = animator_base();
+        assert_eq!(animator_base.forward_time(), 2.5);
+        assert_eq!(animator_base.pong_time(), 2.5);
+
+        animator_base.custom_pong_values = true;
+        assert_eq!(animator_base.pong_time(), 4.0);
+    }
+
+    #[test]
+    fn test_cycle_time() {
+        let mut animator_base = AnimatorBase {
+            custom_pong_values: true,
+            ..animator_base()
+        };
+        assert_eq!(animator_base.cycle_time(), Some(6.5));
+
+        animator_base.time_offset = 1.0;
+        assert_eq!(animator_base.cycle_time(), Some(6.5));
+
+        animator_base.default_action = AnimatorBaseTriggerAction::PlayReverse.into();
+        assert_eq!(animator_base.cycle_time(), Some(2.5));
+
+        animator_base.default_action = AnimatorBaseTriggerAction::Stop.into();
+        assert_eq!(animator_base.cycle_time(), None);
+
+        animator_base.default_action = AnimatorBaseTriggerAction::PingPong.into();
+        animator_base.extend = true;
+        assert_eq!(animator_base.cycle_time(), None);
+
+        animator_base.extend = false;
+        animator_base.loop_ = false;
+        assert_eq!(animator_base.cycle_time(), None);
+    }
+}
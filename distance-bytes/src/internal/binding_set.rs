@@ -0,0 +1,156 @@
+use crate::component::{ControlScheme, DeviceToSchemeLinks, ToolInputCombos, Transform};
+use crate::internal::Serializable;
+use crate::{Component, ComponentData, GameObject};
+use anyhow::{format_err, Result};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Seek, Write};
+use std::path::Path;
+
+/// Every input binding from a control settings file. This serializes with serde, so a binding set
+/// can be exported to and imported from JSON or any other serde format.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct BindingSet {
+    pub schemes: Vec<ControlScheme>,
+    pub device_links: DeviceToSchemeLinks,
+    pub tool_combos: ToolInputCombos,
+}
+
+impl BindingSet {
+    /// Extracts every `ControlScheme` and the `DeviceToSchemeLinks` and `ToolInputCombos`
+    /// components from an object hierarchy, failing if either of the latter is missing.
+    pub fn from_game_object(game_object: &GameObject) -> Result<BindingSet> {
+        let mut schemes = Vec::new();
+        let mut device_links = None;
+        let mut tool_combos = None;
+        for component in game_object
+            .descendants()
+            .flat_map(|game_object| &game_object.components)
+        {
+            match &component.data {
+                ComponentData::ControlScheme(x) => schemes.push(x.clone()),
+                ComponentData::DeviceToSchemeLinks(x) if device_links.is_none() => {
+                    device_links = Some(x.clone())
+                }
+                ComponentData::ToolInputCombos(x) if tool_combos.is_none() => {
+                    tool_combos = Some(x.clone())
+                }
+                _ => {}
+            }
+        }
+
+        Ok(BindingSet {
+            schemes,
+            device_links: device_links
+                .ok_or_else(|| format_err!("binding set has no DeviceToSchemeLinks component"))?,
+            tool_combos: tool_combos
+                .ok_or_else(|| format_err!("binding set has no ToolInputCombos component"))?,
+        })
+    }
+
+    /// Wraps the binding set in a `GameObject` with a default `Transform`, holding one component
+    /// per scheme followed by the device links and tool combos.
+    pub fn into_game_object(self) -> GameObject {
+        let mut components = vec![Component {
+            version: Transform::VERSION,
+            guid: 0,
+            data: ComponentData::Transform(Transform::default()),
+        }];
+        components.extend(self.schemes.into_iter().map(|scheme| Component {
+            version: ControlScheme::VERSION,
+            guid: 0,
+            data: ComponentData::ControlScheme(scheme),
+        }));
+        components.push(Component {
+            version: DeviceToSchemeLinks::VERSION,
+            guid: 0,
+            data: ComponentData::DeviceToSchemeLinks(self.device_links),
+        });
+        components.push(Component {
+            version: ToolInputCombos::VERSION,
+            guid: 0,
+            data: ComponentData::ToolInputCombos(self.tool_combos),
+        });
+
+        GameObject {
+            name: "InputSettings".to_owned(),
+            guid: 0,
+            components,
+        }
+    }
+
+    pub fn read_from_reader(reader: impl Read + Seek) -> Result<BindingSet> {
+        BindingSet::from_game_object(&GameObject::read_from_reader(reader)?)
+    }
+
+    pub fn read_from_file(path: impl AsRef<Path>) -> Result<BindingSet> {
+        BindingSet::from_game_object(&GameObject::read_from_file(path)?)
+    }
+
+    pub fn write_to_writer(self, writer: impl Write + Seek) -> Result<()> {
+        self.into_game_object().write_to_writer(writer)
+    }
+
+    pub fn write_to_file(self, path: impl AsRef<Path>) -> Result<()> {
+        self.into_game_object().write_to_file(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::{DeviceToSchemeLink, InputAction, InputBinding, ToolInputCombo};
+    use std::io::Cursor;
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let scheme = |name: &str, primary: &str| ControlScheme {
+            name: Some(name.to_owned()),
+            bindings: vec![InputBinding {
+                action: InputAction::Gas.into(),
+                primary: Some(primary.to_owned()),
+                ..Default::default()
+            }],
+        };
+        let binding_set = BindingSet {
+            schemes: vec![
+                scheme("Keyboard", "Keyboard/W"),
+                scheme("Gamepad", "Gamepad/RightTrigger"),
+            ],
+            device_links: DeviceToSchemeLinks {
+                links: vec![DeviceToSchemeLink {
+                    device_name: Some("Keyboard".to_owned()),
+                    scheme_name: Some("Keyboard".to_owned()),
+                }],
+            },
+            tool_combos: ToolInputCombos {
+                combos: vec![ToolInputCombo {
+                    tool_name: Some("DuplicateTool".to_owned()),
+                    inputs: vec![Some("Keyboard/D".to_owned())],
+                }],
+            },
+        };
+
+        let mut buf = Cursor::new(Vec::new());
+        binding_set.clone().write_to_writer(&mut buf).unwrap();
+        buf.set_position(0);
+
+        assert_eq!(BindingSet::read_from_reader(buf).unwrap(), binding_set);
+    }
+
+    #[test]
+    fn test_missing_components() {
+        let game_object = BindingSet::default().into_game_object();
+        let without_links = GameObject {
+            components: game_object
+                .components
+                .into_iter()
+                .filter(|component| {
+                    !matches!(component.data, ComponentData::DeviceToSchemeLinks(_))
+                })
+                .collect(),
+            ..game_object
+        };
+
+        assert!(BindingSet::from_game_object(&without_links).is_err());
+    }
+}
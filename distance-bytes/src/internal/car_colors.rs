@@ -1,6 +1,6 @@
 use crate::internal::{Serializable, Visitor};
 use crate::Color;
-use anyhow::Result;
+use anyhow::{format_err, Result};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
@@ -11,6 +11,37 @@ pub struct CarColors {
     pub sparkle: Color,
 }
 
+impl CarColors {
+    /// Formats the colors as four space-separated hex colors, in the order primary, secondary,
+    /// glow, sparkle. Fails if any color can't be written as hex, see [`Color::to_hex`].
+    pub fn to_hex(&self) -> Result<String> {
+        Ok(format!(
+            "{} {} {} {}",
+            self.primary.to_hex()?,
+            self.secondary.to_hex()?,
+            self.glow.to_hex()?,
+            self.sparkle.to_hex()?
+        ))
+    }
+
+    /// Parses colors in the format written by [`to_hex`](CarColors::to_hex).
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let colors = hex
+            .split_whitespace()
+            .map(Color::from_hex)
+            .collect::<Result<Vec<_>>>()?;
+        match colors.as_slice() {
+            &[primary, secondary, glow, sparkle] => Ok(CarColors {
+                primary,
+                secondary,
+                glow,
+                sparkle,
+            }),
+            _ => Err(format_err!("expected 4 hex colors, found {}", colors.len())),
+        }
+    }
+}
+
 impl Serializable for CarColors {
     const VERSION: i32 = 0;
 
@@ -23,3 +54,36 @@ impl Serializable for CarColors {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_round_trip() {
+        let colors = CarColors::from_hex("#ff0000 #FF8000 ffff0080 #000000").unwrap();
+        assert_eq!(colors.primary, Color::new(1.0, 0.0, 0.0, 1.0));
+        assert_eq!(colors.glow.a, 128.0 / 255.0);
+        assert_eq!(
+            colors.to_hex().unwrap(),
+            "#ff0000 #ff8000 #ffff0080 #000000"
+        );
+    }
+
+    #[test]
+    fn test_invalid_hex() {
+        assert!(CarColors::from_hex("#ff0000 #ff0000 #ff0000").is_err());
+        assert!(CarColors::from_hex("#ff0000 #ff0000 #ff0000 #ff00").is_err());
+        assert!(CarColors::from_hex("#ff0000 #ff0000 #ff0000 #gg0000").is_err());
+        assert!(CarColors::from_hex("#ff0000 #ff0000 #ff0000 #ff00é").is_err());
+    }
+
+    #[test]
+    fn test_out_of_range_to_hex() {
+        let mut colors = CarColors::from_hex("#ff0000 #ff0000 #ff0000 #ff0000").unwrap();
+        colors.glow = Color::new(2.0, 0.5, 0.0, 1.0);
+        assert!(colors.to_hex().is_err());
+        colors.glow = Color::new(f32::NAN, 0.5, 0.0, 1.0);
+        assert!(colors.to_hex().is_err());
+    }
+}
@@ -1,38 +1,81 @@
+pub use adventure_ability_settings::AdventureAbilitySettings;
 pub use animated::{Animated, AnimatedMotionType, AnimatedTranslateType};
+pub use arena_car_spawner::{ArenaCarSpawner, ArenaCarSpawnerTeam};
 pub use box_collider::BoxCollider;
 pub use capsule_collider::CapsuleCollider;
 pub use car_replay_data::CarReplayData;
+pub use car_spawner::CarSpawner;
+pub use control_scheme::{ControlScheme, InputAction, InputBinding};
 pub use custom_name::CustomName;
+pub use device_to_scheme_links::{DeviceToSchemeLink, DeviceToSchemeLinks};
+pub use enable_abilities_trigger::EnableAbilitiesTrigger;
+pub use fog_skybox_ambient_change_trigger::FogSkyboxAmbientChangeTrigger;
+pub use force_volume::{ForceVolume, ForceVolumeForceType};
 pub use golden_simples::{GoldenSimples, GoldenSimplesPresets};
+pub use gravity_toggle::GravityToggle;
 pub use group::{Group, GroupInspectChildrenType};
+pub use infinite_cooldown_trigger::InfiniteCooldownTrigger;
+pub use interpolate_to_position_on_trigger::InterpolateToPositionOnTrigger;
+pub use interpolate_to_rotation_on_trigger::InterpolateToRotationOnTrigger;
+pub use level_editor_car_spawner::LevelEditorCarSpawner;
 pub use level_infos::LevelInfos;
+pub use level_playlist::{LevelPlaylist, LevelPlaylistEntry};
+pub use local_leaderboard::{LeaderboardEntry, LocalLeaderboard};
 pub use mesh_renderer::MeshRenderer;
+pub use particle_emit_logic::ParticleEmitLogic;
+pub use particles_gpu::ParticlesGPU;
 pub use profile_progress::ProfileProgress;
 pub use profile_stats::ProfileStats;
+pub use race_start_car_spawner::RaceStartCarSpawner;
+pub use set_abilities_trigger::SetAbilitiesTrigger;
 pub use sphere_collider::SphereCollider;
+pub use spherical_gravity::SphericalGravity;
+pub use tool_input_combos::{ToolInputCombo, ToolInputCombos};
 pub use track_link::TrackLink;
-pub use transform::Transform;
+pub use transform::{Transform, WorldTransform};
 pub use z_event_listener::ZEventListener;
 pub use z_event_trigger::ZEventTrigger;
 
 use crate::internal::Serializable;
+use crate::Animator;
 use anyhow::{format_err, Result};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde::{Deserialize, Serialize};
 use std::any::TypeId;
 
+mod adventure_ability_settings;
 mod animated;
+mod arena_car_spawner;
 mod box_collider;
 mod capsule_collider;
 mod car_replay_data;
+mod car_spawner;
+mod control_scheme;
 mod custom_name;
+mod device_to_scheme_links;
+mod enable_abilities_trigger;
+mod fog_skybox_ambient_change_trigger;
+mod force_volume;
 mod golden_simples;
+mod gravity_toggle;
 mod group;
+mod infinite_cooldown_trigger;
+mod interpolate_to_position_on_trigger;
+mod interpolate_to_rotation_on_trigger;
+mod level_editor_car_spawner;
 mod level_infos;
+mod level_playlist;
+mod local_leaderboard;
 mod mesh_renderer;
+mod particle_emit_logic;
+mod particles_gpu;
 mod profile_progress;
 mod profile_stats;
+mod race_start_car_spawner;
+mod set_abilities_trigger;
 mod sphere_collider;
+mod spherical_gravity;
+mod tool_input_combos;
 mod track_link;
 mod transform;
 mod z_event_listener;
@@ -116,31 +159,31 @@ impl Component {
             ComponentId::ControlsSettings => builder.raw(ComponentData::ControlsSettings),
             ComponentId::Profile => builder.raw(ComponentData::Profile),
             ComponentId::LevelSet => unserializable(),
-            ComponentId::ToolInputCombos => builder.raw(ComponentData::ToolInputCombos),
+            ComponentId::ToolInputCombos => builder.implemented(ComponentData::ToolInputCombos, ToolInputCombos::VERSION),
             ComponentId::ColorPreset => builder.raw(ComponentData::ColorPreset),
-            ComponentId::LocalLeaderboard => builder.raw(ComponentData::LocalLeaderboard),
+            ComponentId::LocalLeaderboard => builder.implemented(ComponentData::LocalLeaderboard, LocalLeaderboard::VERSION),
             ComponentId::AxisRotationLogic => builder.raw(ComponentData::AxisRotationLogic),
-            ComponentId::ParticleEmitLogic => builder.raw(ComponentData::ParticleEmitLogic),
+            ComponentId::ParticleEmitLogic => builder.implemented(ComponentData::ParticleEmitLogic, ParticleEmitLogic::VERSION),
             ComponentId::VirusSpiritSpawner => builder.raw(ComponentData::VirusSpiritSpawner),
             ComponentId::GlitchTrigger => unserializable(),
             ComponentId::Teleporter => unserializable(),
             ComponentId::PulseRotateOnTrigger => builder.raw(ComponentData::PulseRotateOnTrigger),
             ComponentId::TeleporterEntrance => builder.raw(ComponentData::TeleporterEntrance),
             ComponentId::TeleporterExit => builder.raw(ComponentData::TeleporterExit),
-            ComponentId::ControlScheme => builder.raw(ComponentData::ControlScheme),
-            ComponentId::DeviceToSchemeLinks => builder.raw(ComponentData::DeviceToSchemeLinks),
+            ComponentId::ControlScheme => builder.implemented(ComponentData::ControlScheme, ControlScheme::VERSION),
+            ComponentId::DeviceToSchemeLinks => builder.implemented(ComponentData::DeviceToSchemeLinks, DeviceToSchemeLinks::VERSION),
             ComponentId::ObjectSpawnCircle => builder.raw(ComponentData::ObjectSpawnCircle),
-            ComponentId::InterpolateToPositionOnTrigger => builder.raw(ComponentData::InterpolateToPositionOnTrigger),
+            ComponentId::InterpolateToPositionOnTrigger => builder.implemented(ComponentData::InterpolateToPositionOnTrigger, InterpolateToPositionOnTrigger::VERSION),
             ComponentId::EngageBrokenPieces => builder.raw(ComponentData::EngageBrokenPieces),
-            ComponentId::GravityToggle => builder.raw(ComponentData::GravityToggle),
-            ComponentId::CarSpawner => builder.raw(ComponentData::CarSpawner),
-            ComponentId::RaceStartCarSpawner => builder.raw(ComponentData::RaceStartCarSpawner),
-            ComponentId::LevelEditorCarSpawner => builder.raw(ComponentData::LevelEditorCarSpawner),
+            ComponentId::GravityToggle => builder.implemented(ComponentData::GravityToggle, GravityToggle::VERSION),
+            ComponentId::CarSpawner => builder.implemented(ComponentData::CarSpawner, CarSpawner::VERSION),
+            ComponentId::RaceStartCarSpawner => builder.implemented(ComponentData::RaceStartCarSpawner, RaceStartCarSpawner::VERSION),
+            ComponentId::LevelEditorCarSpawner => builder.implemented(ComponentData::LevelEditorCarSpawner, LevelEditorCarSpawner::VERSION),
             ComponentId::OnlyActiveInLevelEditor => unserializable(),
             ComponentId::InfoDisplayLogic => builder.raw(ComponentData::InfoDisplayLogic),
             ComponentId::MusicTrigger => builder.raw(ComponentData::MusicTrigger),
             ComponentId::TabPopulator => builder.raw(ComponentData::TabPopulator),
-            ComponentId::AdventureAbilitySettings => builder.raw(ComponentData::AdventureAbilitySettings),
+            ComponentId::AdventureAbilitySettings => builder.implemented(ComponentData::AdventureAbilitySettings, AdventureAbilitySettings::VERSION),
             ComponentId::IndicatorDisplayLogic => builder.raw(ComponentData::IndicatorDisplayLogic),
             ComponentId::PulseCoreLogic => builder.raw(ComponentData::PulseCoreLogic),
             ComponentId::PulseAll => builder.raw(ComponentData::PulseAll),
@@ -152,13 +195,13 @@ impl Component {
             ComponentId::QuarantineTrigger => builder.raw(ComponentData::QuarantineTrigger),
             ComponentId::CarScreenTextDecodeTrigger => builder.raw(ComponentData::CarScreenTextDecodeTrigger),
             ComponentId::GlitchFieldLogic => builder.raw(ComponentData::GlitchFieldLogic),
-            ComponentId::FogSkyboxAmbientChangeTrigger => builder.raw(ComponentData::FogSkyboxAmbientChangeTrigger),
+            ComponentId::FogSkyboxAmbientChangeTrigger => builder.implemented(ComponentData::FogSkyboxAmbientChangeTrigger, FogSkyboxAmbientChangeTrigger::VERSION),
             ComponentId::FinalCountdownLogic => builder.raw(ComponentData::FinalCountdownLogic),
             ComponentId::SetActiveOnIntroCutsceneStarted => builder.raw(ComponentData::SetActiveOnIntroCutsceneStarted),
             ComponentId::SphericalGravityTrigger => unserializable(),
             ComponentId::RaceEndLogic => builder.raw(ComponentData::RaceEndLogic),
-            ComponentId::EnableAbilitiesTrigger => builder.raw(ComponentData::EnableAbilitiesTrigger),
-            ComponentId::SphericalGravity => builder.raw(ComponentData::SphericalGravity),
+            ComponentId::EnableAbilitiesTrigger => builder.implemented(ComponentData::EnableAbilitiesTrigger, EnableAbilitiesTrigger::VERSION),
+            ComponentId::SphericalGravity => builder.implemented(ComponentData::SphericalGravity, SphericalGravity::VERSION),
             ComponentId::GlobalFogLogic => unserializable(),
             ComponentId::CreditsNameOrbLogic => builder.raw(ComponentData::CreditsNameOrbLogic),
             ComponentId::DisableLocalCarWarnings => builder.raw(ComponentData::DisableLocalCarWarnings),
@@ -168,7 +211,7 @@ impl Component {
             ComponentId::RumbleZone => builder.raw(ComponentData::RumbleZone),
             ComponentId::HideOnVirusSpiritEvent => builder.raw(ComponentData::HideOnVirusSpiritEvent),
             ComponentId::TrackAttachment => builder.raw(ComponentData::TrackAttachment),
-            ComponentId::LevelPlaylist => builder.raw(ComponentData::LevelPlaylist),
+            ComponentId::LevelPlaylist => builder.implemented(ComponentData::LevelPlaylist, LevelPlaylist::VERSION),
             ComponentId::ProfileProgress => builder.implemented(ComponentData::ProfileProgress, ProfileProgress::VERSION),
             ComponentId::GeneralSettings => builder.raw(ComponentData::GeneralSettings),
             ComponentId::ReplayAllPurposeTrigger => unserializable(),
@@ -192,7 +235,7 @@ impl Component {
             ComponentId::VirusSpiritWarpTeaserLogic => builder.raw(ComponentData::VirusSpiritWarpTeaserLogic),
             ComponentId::CarReplayData => builder.implemented(ComponentData::CarReplayData, CarReplayData::VERSION),
             ComponentId::LevelImageCamera => builder.raw(ComponentData::LevelImageCamera),
-            ComponentId::ParticlesGPU => builder.raw(ComponentData::ParticlesGPU),
+            ComponentId::ParticlesGPU => builder.implemented(ComponentData::ParticlesGPU, ParticlesGPU::VERSION),
             ComponentId::KillGridBox => builder.raw(ComponentData::KillGridBox),
             ComponentId::SetActiveAfterWarp => builder.raw(ComponentData::SetActiveAfterWarp),
             ComponentId::AmbientAudioObject => builder.raw(ComponentData::AmbientAudioObject),
@@ -205,7 +248,7 @@ impl Component {
             ComponentId::VRSettings => builder.raw(ComponentData::VRSettings),
             ComponentId::CutsceneCamera => builder.raw(ComponentData::CutsceneCamera),
             ComponentId::ProfileStats => builder.implemented(|x| ComponentData::ProfileStats(Box::new(x)), ProfileStats::VERSION),
-            ComponentId::InterpolateToRotationOnTrigger => builder.raw(ComponentData::InterpolateToRotationOnTrigger),
+            ComponentId::InterpolateToRotationOnTrigger => builder.implemented(ComponentData::InterpolateToRotationOnTrigger, InterpolateToRotationOnTrigger::VERSION),
             ComponentId::MoveAlongAttachedTrack => builder.raw(ComponentData::MoveAlongAttachedTrack),
             ComponentId::ShowDuringGlitch => builder.raw(ComponentData::ShowDuringGlitch),
             ComponentId::AddCameraNoise => builder.raw(ComponentData::AddCameraNoise),
@@ -215,14 +258,14 @@ impl Component {
             ComponentId::CutsceneCamForTrailer => builder.raw(ComponentData::CutsceneCamForTrailer),
             ComponentId::LevelInfos => builder.implemented(ComponentData::LevelInfos, LevelInfos::VERSION),
             ComponentId::AchievementTrigger => builder.raw(ComponentData::AchievementTrigger),
-            ComponentId::ArenaCarSpawner => builder.raw(ComponentData::ArenaCarSpawner),
+            ComponentId::ArenaCarSpawner => builder.implemented(ComponentData::ArenaCarSpawner, ArenaCarSpawner::VERSION),
             ComponentId::Animated => builder.implemented(ComponentData::Animated, Animated::VERSION),
             ComponentId::BlinkInTrigger => builder.raw(ComponentData::BlinkInTrigger),
             ComponentId::CarScreenImageTrigger => builder.raw(ComponentData::CarScreenImageTrigger),
             ComponentId::ExcludeFromEMP => builder.raw(ComponentData::ExcludeFromEMP),
-            ComponentId::InfiniteCooldownTrigger => builder.raw(ComponentData::InfiniteCooldownTrigger),
+            ComponentId::InfiniteCooldownTrigger => builder.implemented(ComponentData::InfiniteCooldownTrigger, InfiniteCooldownTrigger::VERSION),
             ComponentId::DiscoverableStuntArea => builder.raw(ComponentData::DiscoverableStuntArea),
-            ComponentId::ForceVolume => builder.raw(ComponentData::ForceVolume),
+            ComponentId::ForceVolume => builder.implemented(ComponentData::ForceVolume, ForceVolume::VERSION),
             ComponentId::AdventureModeCompleteTrigger => builder.raw(ComponentData::AdventureModeCompleteTrigger),
             ComponentId::CountdownTextMeshLogic => builder.raw(ComponentData::CountdownTextMeshLogic),
             ComponentId::AbilitySignButtonColorLogic => builder.raw(ComponentData::AbilitySignButtonColorLogic),
@@ -235,7 +278,7 @@ impl Component {
             ComponentId::AudioEffectZone => builder.raw(ComponentData::AudioEffectZone),
             ComponentId::CinematicCamera => builder.raw(ComponentData::CinematicCamera),
             ComponentId::CinematicCameraFocalPoint => builder.raw(ComponentData::CinematicCameraFocalPoint),
-            ComponentId::SetAbilitiesTrigger => builder.raw(ComponentData::SetAbilitiesTrigger),
+            ComponentId::SetAbilitiesTrigger => builder.implemented(ComponentData::SetAbilitiesTrigger, SetAbilitiesTrigger::VERSION),
             ComponentId::LostToEchoesIntroCutscene => builder.raw(ComponentData::LostToEchoesIntroCutscene),
             ComponentId::CutsceneText => builder.raw(ComponentData::CutsceneText),
             ComponentId::UltraPlanet => builder.raw(ComponentData::UltraPlanet),
@@ -790,28 +833,28 @@ pub enum ComponentData {
     AudioSettings(RawComponentData),
     ControlsSettings(RawComponentData),
     Profile(RawComponentData),
-    ToolInputCombos(RawComponentData),
+    ToolInputCombos(ToolInputCombos),
     ColorPreset(RawComponentData),
-    LocalLeaderboard(RawComponentData),
+    LocalLeaderboard(LocalLeaderboard),
     AxisRotationLogic(RawComponentData),
-    ParticleEmitLogic(RawComponentData),
+    ParticleEmitLogic(ParticleEmitLogic),
     VirusSpiritSpawner(RawComponentData),
     PulseRotateOnTrigger(RawComponentData),
     TeleporterEntrance(RawComponentData),
     TeleporterExit(RawComponentData),
-    ControlScheme(RawComponentData),
-    DeviceToSchemeLinks(RawComponentData),
+    ControlScheme(ControlScheme),
+    DeviceToSchemeLinks(DeviceToSchemeLinks),
     ObjectSpawnCircle(RawComponentData),
-    InterpolateToPositionOnTrigger(RawComponentData),
+    InterpolateToPositionOnTrigger(InterpolateToPositionOnTrigger),
     EngageBrokenPieces(RawComponentData),
-    GravityToggle(RawComponentData),
-    CarSpawner(RawComponentData),
-    RaceStartCarSpawner(RawComponentData),
-    LevelEditorCarSpawner(RawComponentData),
+    GravityToggle(GravityToggle),
+    CarSpawner(CarSpawner),
+    RaceStartCarSpawner(RaceStartCarSpawner),
+    LevelEditorCarSpawner(LevelEditorCarSpawner),
     InfoDisplayLogic(RawComponentData),
     MusicTrigger(RawComponentData),
     TabPopulator(RawComponentData),
-    AdventureAbilitySettings(RawComponentData),
+    AdventureAbilitySettings(AdventureAbilitySettings),
     IndicatorDisplayLogic(RawComponentData),
     PulseCoreLogic(RawComponentData),
     PulseAll(RawComponentData),
@@ -823,12 +866,12 @@ pub enum ComponentData {
     QuarantineTrigger(RawComponentData),
     CarScreenTextDecodeTrigger(RawComponentData),
     GlitchFieldLogic(RawComponentData),
-    FogSkyboxAmbientChangeTrigger(RawComponentData),
+    FogSkyboxAmbientChangeTrigger(FogSkyboxAmbientChangeTrigger),
     FinalCountdownLogic(RawComponentData),
     SetActiveOnIntroCutsceneStarted(RawComponentData),
     RaceEndLogic(RawComponentData),
-    EnableAbilitiesTrigger(RawComponentData),
-    SphericalGravity(RawComponentData),
+    EnableAbilitiesTrigger(EnableAbilitiesTrigger),
+    SphericalGravity(SphericalGravity),
     CreditsNameOrbLogic(RawComponentData),
     DisableLocalCarWarnings(RawComponentData),
     CustomName(CustomName),
@@ -837,7 +880,7 @@ pub enum ComponentData {
     RumbleZone(RawComponentData),
     HideOnVirusSpiritEvent(RawComponentData),
     TrackAttachment(RawComponentData),
-    LevelPlaylist(RawComponentData),
+    LevelPlaylist(LevelPlaylist),
     ProfileProgress(ProfileProgress),
     GeneralSettings(RawComponentData),
     WorkshopPublishedFileInfos(RawComponentData),
@@ -854,7 +897,7 @@ pub enum ComponentData {
     VirusSpiritWarpTeaserLogic(RawComponentData),
     CarReplayData(CarReplayData),
     LevelImageCamera(RawComponentData),
-    ParticlesGPU(RawComponentData),
+    ParticlesGPU(ParticlesGPU),
     KillGridBox(RawComponentData),
     GoldenSimples(GoldenSimples),
     SetActiveAfterWarp(RawComponentData),
@@ -868,7 +911,7 @@ pub enum ComponentData {
     VRSettings(RawComponentData),
     CutsceneCamera(RawComponentData),
     ProfileStats(Box<ProfileStats>),
-    InterpolateToRotationOnTrigger(RawComponentData),
+    InterpolateToRotationOnTrigger(InterpolateToRotationOnTrigger),
     MoveAlongAttachedTrack(RawComponentData),
     ShowDuringGlitch(RawComponentData),
     AddCameraNoise(RawComponentData),
@@ -878,14 +921,14 @@ pub enum ComponentData {
     CutsceneCamForTrailer(RawComponentData),
     LevelInfos(LevelInfos),
     AchievementTrigger(RawComponentData),
-    ArenaCarSpawner(RawComponentData),
+    ArenaCarSpawner(ArenaCarSpawner),
     Animated(Animated),
     BlinkInTrigger(RawComponentData),
     CarScreenImageTrigger(RawComponentData),
     ExcludeFromEMP(RawComponentData),
-    InfiniteCooldownTrigger(RawComponentData),
+    InfiniteCooldownTrigger(InfiniteCooldownTrigger),
     DiscoverableStuntArea(RawComponentData),
-    ForceVolume(RawComponentData),
+    ForceVolume(ForceVolume),
     AdventureModeCompleteTrigger(RawComponentData),
     CountdownTextMeshLogic(RawComponentData),
     AbilitySignButtonColorLogic(RawComponentData),
@@ -897,7 +940,7 @@ pub enum ComponentData {
     AudioEffectZone(RawComponentData),
     CinematicCamera(RawComponentData),
     CinematicCameraFocalPoint(RawComponentData),
-    SetAbilitiesTrigger(RawComponentData),
+    SetAbilitiesTrigger(SetAbilitiesTrigger),
     LostToEchoesIntroCutscene(RawComponentData),
     CutsceneText(RawComponentData),
     UltraPlanet(RawComponentData),
@@ -1128,6 +1171,26 @@ impl ComponentData {
         }
     }
 
+    /// Returns the component as an [`Animator`], if it's driven by an `AnimatorBase`.
+    pub fn as_animator(&self) -> Option<&dyn Animator> {
+        match self {
+            ComponentData::Animated(x) => Some(x),
+            ComponentData::InterpolateToPositionOnTrigger(x) => Some(x),
+            ComponentData::InterpolateToRotationOnTrigger(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Mutable version of [`as_animator`](ComponentData::as_animator).
+    pub fn as_animator_mut(&mut self) -> Option<&mut dyn Animator> {
+        match self {
+            ComponentData::Animated(x) => Some(x),
+            ComponentData::InterpolateToPositionOnTrigger(x) => Some(x),
+            ComponentData::InterpolateToRotationOnTrigger(x) => Some(x),
+            _ => None,
+        }
+    }
+
     pub(crate) fn dispatch<D: ComponentDataDispatch>(&mut self, mut dispatcher: D) -> Result<()> {
         match self {
             ComponentData::Transform(data) => dispatcher.implemented(data),
@@ -1162,28 +1225,28 @@ impl ComponentData {
             ComponentData::AudioSettings(data) => dispatcher.raw(data),
             ComponentData::ControlsSettings(data) => dispatcher.raw(data),
             ComponentData::Profile(data) => dispatcher.raw(data),
-            ComponentData::ToolInputCombos(data) => dispatcher.raw(data),
+            ComponentData::ToolInputCombos(data) => dispatcher.implemented(data),
             ComponentData::ColorPreset(data) => dispatcher.raw(data),
-            ComponentData::LocalLeaderboard(data) => dispatcher.raw(data),
+            ComponentData::LocalLeaderboard(data) => dispatcher.implemented(data),
             ComponentData::AxisRotationLogic(data) => dispatcher.raw(data),
-            ComponentData::ParticleEmitLogic(data) => dispatcher.raw(data),
+            ComponentData::ParticleEmitLogic(data) => dispatcher.implemented(data),
             ComponentData::VirusSpiritSpawner(data) => dispatcher.raw(data),
             ComponentData::PulseRotateOnTrigger(data) => dispatcher.raw(data),
             ComponentData::TeleporterEntrance(data) => dispatcher.raw(data),
             ComponentData::TeleporterExit(data) => dispatcher.raw(data),
-            ComponentData::ControlScheme(data) => dispatcher.raw(data),
-            ComponentData::DeviceToSchemeLinks(data) => dispatcher.raw(data),
+            ComponentData::ControlScheme(data) => dispatcher.implemented(data),
+            ComponentData::DeviceToSchemeLinks(data) => dispatcher.implemented(data),
             ComponentData::ObjectSpawnCircle(data) => dispatcher.raw(data),
-            ComponentData::InterpolateToPositionOnTrigger(data) => dispatcher.raw(data),
+            ComponentData::InterpolateToPositionOnTrigger(data) => dispatcher.implemented(data),
             ComponentData::EngageBrokenPieces(data) => dispatcher.raw(data),
-            ComponentData::GravityToggle(data) => dispatcher.raw(data),
-            ComponentData::CarSpawner(data) => dispatcher.raw(data),
-            ComponentData::RaceStartCarSpawner(data) => dispatcher.raw(data),
-            ComponentData::LevelEditorCarSpawner(data) => dispatcher.raw(data),
+            ComponentData::GravityToggle(data) => dispatcher.implemented(data),
+            ComponentData::CarSpawner(data) => dispatcher.implemented(data),
+            ComponentData::RaceStartCarSpawner(data) => dispatcher.implemented(data),
+            ComponentData::LevelEditorCarSpawner(data) => dispatcher.implemented(data),
             ComponentData::InfoDisplayLogic(data) => dispatcher.raw(data),
             ComponentData::MusicTrigger(data) => dispatcher.raw(data),
             ComponentData::TabPopulator(data) => dispatcher.raw(data),
-            ComponentData::AdventureAbilitySettings(data) => dispatcher.raw(data),
+            ComponentData::AdventureAbilitySettings(data) => dispatcher.implemented(data),
             ComponentData::IndicatorDisplayLogic(data) => dispatcher.raw(data),
             ComponentData::PulseCoreLogic(data) => dispatcher.raw(data),
             ComponentData::PulseAll(data) => dispatcher.raw(data),
@@ -1195,12 +1258,12 @@ impl ComponentData {
             ComponentData::QuarantineTrigger(data) => dispatcher.raw(data),
             ComponentData::CarScreenTextDecodeTrigger(data) => dispatcher.raw(data),
             ComponentData::GlitchFieldLogic(data) => dispatcher.raw(data),
-            ComponentData::FogSkyboxAmbientChangeTrigger(data) => dispatcher.raw(data),
+            ComponentData::FogSkyboxAmbientChangeTrigger(data) => dispatcher.implemented(data),
             ComponentData::FinalCountdownLogic(data) => dispatcher.raw(data),
             ComponentData::SetActiveOnIntroCutsceneStarted(data) => dispatcher.raw(data),
             ComponentData::RaceEndLogic(data) => dispatcher.raw(data),
-            ComponentData::EnableAbilitiesTrigger(data) => dispatcher.raw(data),
-            ComponentData::SphericalGravity(data) => dispatcher.raw(data),
+            ComponentData::EnableAbilitiesTrigger(data) => dispatcher.implemented(data),
+            ComponentData::SphericalGravity(data) => dispatcher.implemented(data),
             ComponentData::CreditsNameOrbLogic(data) => dispatcher.raw(data),
             ComponentData::DisableLocalCarWarnings(data) => dispatcher.raw(data),
             ComponentData::CustomName(data) => dispatcher.implemented(data),
@@ -1209,7 +1272,7 @@ impl ComponentData {
             ComponentData::RumbleZone(data) => dispatcher.raw(data),
             ComponentData::HideOnVirusSpiritEvent(data) => dispatcher.raw(data),
             ComponentData::TrackAttachment(data) => dispatcher.raw(data),
-            ComponentData::LevelPlaylist(data) => dispatcher.raw(data),
+            ComponentData::LevelPlaylist(data) => dispatcher.implemented(data),
             ComponentData::ProfileProgress(data) => dispatcher.implemented(data),
             ComponentData::GeneralSettings(data) => dispatcher.raw(data),
             ComponentData::WorkshopPublishedFileInfos(data) => dispatcher.raw(data),
@@ -1226,7 +1289,7 @@ impl ComponentData {
             ComponentData::VirusSpiritWarpTeaserLogic(data) => dispatcher.raw(data),
             ComponentData::CarReplayData(data) => dispatcher.implemented(data),
             ComponentData::LevelImageCamera(data) => dispatcher.raw(data),
-            ComponentData::ParticlesGPU(data) => dispatcher.raw(data),
+            ComponentData::ParticlesGPU(data) => dispatcher.implemented(data),
             ComponentData::KillGridBox(data) => dispatcher.raw(data),
             ComponentData::SetActiveAfterWarp(data) => dispatcher.raw(data),
             ComponentData::AmbientAudioObject(data) => dispatcher.raw(data),
@@ -1239,7 +1302,7 @@ impl ComponentData {
             ComponentData::VRSettings(data) => dispatcher.raw(data),
             ComponentData::CutsceneCamera(data) => dispatcher.raw(data),
             ComponentData::ProfileStats(data) => dispatcher.implemented(data),
-            ComponentData::InterpolateToRotationOnTrigger(data) => dispatcher.raw(data),
+            ComponentData::InterpolateToRotationOnTrigger(data) => dispatcher.implemented(data),
             ComponentData::MoveAlongAttachedTrack(data) => dispatcher.raw(data),
             ComponentData::ShowDuringGlitch(data) => dispatcher.raw(data),
             ComponentData::AddCameraNoise(data) => dispatcher.raw(data),
@@ -1249,14 +1312,14 @@ impl ComponentData {
             ComponentData::CutsceneCamForTrailer(data) => dispatcher.raw(data),
             ComponentData::LevelInfos(data) => dispatcher.implemented(data),
             ComponentData::AchievementTrigger(data) => dispatcher.raw(data),
-            ComponentData::ArenaCarSpawner(data) => dispatcher.raw(data),
+            ComponentData::ArenaCarSpawner(data) => dispatcher.implemented(data),
             ComponentData::Animated(data) => dispatcher.implemented(data),
             ComponentData::BlinkInTrigger(data) => dispatcher.raw(data),
             ComponentData::CarScreenImageTrigger(data) => dispatcher.raw(data),
             ComponentData::ExcludeFromEMP(data) => dispatcher.raw(data),
-            ComponentData::InfiniteCooldownTrigger(data) => dispatcher.raw(data),
+            ComponentData::InfiniteCooldownTrigger(data) => dispatcher.implemented(data),
             ComponentData::DiscoverableStuntArea(data) => dispatcher.raw(data),
-            ComponentData::ForceVolume(data) => dispatcher.raw(data),
+            ComponentData::ForceVolume(data) => dispatcher.implemented(data),
             ComponentData::AdventureModeCompleteTrigger(data) => dispatcher.raw(data),
             ComponentData::CountdownTextMeshLogic(data) => dispatcher.raw(data),
             ComponentData::AbilitySignButtonColorLogic(data) => dispatcher.raw(data),
@@ -1268,7 +1331,7 @@ impl ComponentData {
             ComponentData::AudioEffectZone(data) => dispatcher.raw(data),
             ComponentData::CinematicCamera(data) => dispatcher.raw(data),
             ComponentData::CinematicCameraFocalPoint(data) => dispatcher.raw(data),
-            ComponentData::SetAbilitiesTrigger(data) => dispatcher.raw(data),
+            ComponentData::SetAbilitiesTrigger(data) => dispatcher.implemented(data),
             ComponentData::LostToEchoesIntroCutscene(data) => dispatcher.raw(data),
             ComponentData::CutsceneText(data) => dispatcher.raw(data),
             ComponentData::UltraPlanet(data) => dispatcher.raw(data),
@@ -0,0 +1,24 @@
+use crate::internal::{Serializable, Visitor};
+use crate::Abilities;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// The abilities a car starts an adventure level with.
+#[derive(
+    Debug, Copy, Clone, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize,
+)]
+pub struct AdventureAbilitySettings {
+    pub abilities: Abilities,
+    pub show_ability_alerts: bool,
+}
+
+impl Serializable for AdventureAbilitySettings {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        self.abilities.accept(&mut visitor, 0)?;
+        visitor.visit_bool("showAbilityAlerts_", &mut self.show_ability_alerts)?;
+
+        Ok(())
+    }
+}
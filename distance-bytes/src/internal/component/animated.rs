@@ -1,5 +1,5 @@
 use crate::internal::{Serializable, Visitor};
-use crate::{AnimatorBase, Enum, Vector3, ZEROS_VECTOR_3};
+use crate::{Animator, AnimatorBase, Enum, Vector3, ZEROS_VECTOR_3};
 use anyhow::Result;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde::{Deserialize, Serialize};
@@ -73,6 +73,16 @@ impl Default for Animated {
     }
 }
 
+impl Animator for Animated {
+    fn animator_base(&self) -> &AnimatorBase {
+        &self.base
+    }
+
+    fn animator_base_mut(&mut self) -> &mut AnimatorBase {
+        &mut self.base
+    }
+}
+
 impl Serializable for Animated {
     const VERSION: i32 = 11;
 
@@ -0,0 +1,51 @@
+use crate::internal::{Serializable, Visitor};
+use crate::Enum;
+use anyhow::Result;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Copy, Clone, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize,
+)]
+pub struct ArenaCarSpawner {
+    pub team: Enum<ArenaCarSpawnerTeam>,
+    pub spawn_index: i32,
+}
+
+impl Serializable for ArenaCarSpawner {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        visitor.visit_enum("team_", &mut self.team)?;
+        visitor.visit_i32("spawnIndex_", &mut self.spawn_index)?;
+
+        Ok(())
+    }
+}
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Hash,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    IntoPrimitive,
+    TryFromPrimitive,
+)]
+#[repr(i32)]
+pub enum ArenaCarSpawnerTeam {
+    None = 0,
+    Blue = 1,
+    Orange = 2,
+}
+
+impl Default for ArenaCarSpawnerTeam {
+    fn default() -> Self {
+        ArenaCarSpawnerTeam::None
+    }
+}
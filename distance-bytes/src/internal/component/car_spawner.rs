@@ -0,0 +1,40 @@
+use crate::internal::{Serializable, Visitor};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// A start point for cars that can be used in any game mode. See
+/// [`SpawnPoint`](crate::SpawnPoint) for finding a level's spawners and checking them against its
+/// enabled modes.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct CarSpawner {
+    pub spawn_index: i32,
+    pub use_for_all_players: bool,
+    pub spawn_with_velocity: bool,
+    pub initial_speed: f32,
+}
+
+impl Default for CarSpawner {
+    fn default() -> Self {
+        CarSpawner {
+            spawn_index: 0,
+            use_for_all_players: true,
+            spawn_with_velocity: false,
+            initial_speed: 0.0,
+        }
+    }
+}
+
+impl Serializable for CarSpawner {
+    const VERSION: i32 = 1;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, version: i32) -> Result<()> {
+        visitor.visit_i32("spawnIndex_", &mut self.spawn_index)?;
+        visitor.visit_bool("useForAllPlayers_", &mut self.use_for_all_players)?;
+        if version >= 1 {
+            visitor.visit_bool("spawnWithVelocity_", &mut self.spawn_with_velocity)?;
+            visitor.visit_f32("initialSpeed_", &mut self.initial_speed)?;
+        }
+
+        Ok(())
+    }
+}
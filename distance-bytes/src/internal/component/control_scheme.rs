@@ -0,0 +1,191 @@
+use crate::internal::{Serializable, Visitor};
+use crate::Enum;
+use anyhow::Result;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use serde::{Deserialize, Serialize};
+
+/// A named set of input bindings, one per action.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct ControlScheme {
+    pub name: Option<String>,
+    pub bindings: Vec<InputBinding>,
+}
+
+impl ControlScheme {
+    /// Returns the binding for the given action, if the scheme has one.
+    pub fn binding(&self, action: InputAction) -> Option<&InputBinding> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.action == action.into())
+    }
+}
+
+impl Serializable for ControlScheme {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        visitor.visit_string("name_", &mut self.name)?;
+        visitor.visit_array("Bindings", &mut self.bindings, |visitor, binding| {
+            binding.accept(visitor, 0)
+        })?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct InputBinding {
+    pub action: Enum<InputAction>,
+
+    /// Name of the primary input, e.g. `Keyboard/W` or `Gamepad/RightTrigger`
+    pub primary: Option<String>,
+
+    /// Name of the alternative input, if any
+    pub secondary: Option<String>,
+
+    pub invert: bool,
+    pub sensitivity: f32,
+}
+
+impl Default for InputBinding {
+    fn default() -> Self {
+        InputBinding {
+            action: Default::default(),
+            primary: None,
+            secondary: None,
+            invert: false,
+            sensitivity: 1.0,
+        }
+    }
+}
+
+impl Serializable for InputBinding {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        visitor.visit_enum("Action", &mut self.action)?;
+        visitor.visit_string("Primary", &mut self.primary)?;
+        visitor.visit_string("Secondary", &mut self.secondary)?;
+        visitor.visit_bool("Invert", &mut self.invert)?;
+        visitor.visit_f32("Sensitivity", &mut self.sensitivity)?;
+
+        Ok(())
+    }
+}
+
+/// An action a binding can trigger. These values haven't been checked against a real settings
+/// file yet.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Hash,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    IntoPrimitive,
+    TryFromPrimitive,
+)]
+#[repr(i32)]
+pub enum InputAction {
+    Gas = 0,
+    Brake = 1,
+    SteerLeft = 2,
+    SteerRight = 3,
+    Boost = 4,
+    Jump = 5,
+    Grip = 6,
+    Wings = 7,
+    RotateForward = 8,
+    RotateBackward = 9,
+    RotateLeft = 10,
+    RotateRight = 11,
+    RollLeft = 12,
+    RollRight = 13,
+    Horn = 14,
+    Reset = 15,
+    LookBehind = 16,
+    SwitchCamera = 17,
+    ShowScore = 18,
+    Pause = 19,
+}
+
+impl Default for InputAction {
+    fn default() -> Self {
+        InputAction::Gas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internal::component::{
+        DeviceToSchemeLink, DeviceToSchemeLinks, ToolInputCombo, ToolInputCombos,
+    };
+    use crate::internal::test_util;
+    use crate::{Component, ComponentData, GameObject};
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let control_scheme = ControlScheme {
+            name: Some("Keyboard".to_owned()),
+            bindings: vec![
+                InputBinding {
+                    action: InputAction::Gas.into(),
+                    primary: Some("Keyboard/W".to_owned()),
+                    secondary: Some("Keyboard/UpArrow".to_owned()),
+                    ..Default::default()
+                },
+                InputBinding {
+                    action: InputAction::Boost.into(),
+                    primary: Some("Keyboard/Space".to_owned()),
+                    invert: true,
+                    sensitivity: 0.5,
+                    ..Default::default()
+                },
+            ],
+        };
+        let device_to_scheme_links = DeviceToSchemeLinks {
+            links: vec![DeviceToSchemeLink {
+                device_name: Some("Keyboard".to_owned()),
+                scheme_name: Some("Keyboard".to_owned()),
+            }],
+        };
+        let tool_input_combos = ToolInputCombos {
+            combos: vec![ToolInputCombo {
+                tool_name: Some("DuplicateTool".to_owned()),
+                inputs: vec![
+                    Some("Keyboard/LeftControl".to_owned()),
+                    Some("Keyboard/D".to_owned()),
+                ],
+            }],
+        };
+
+        let mut game_object = GameObject {
+            name: "InputSettings".to_owned(),
+            guid: 0,
+            components: vec![
+                Component {
+                    version: ControlScheme::VERSION,
+                    guid: 0,
+                    data: ComponentData::ControlScheme(control_scheme),
+                },
+                Component {
+                    version: DeviceToSchemeLinks::VERSION,
+                    guid: 0,
+                    data: ComponentData::DeviceToSchemeLinks(device_to_scheme_links),
+                },
+                Component {
+                    version: ToolInputCombos::VERSION,
+                    guid: 0,
+                    data: ComponentData::ToolInputCombos(tool_input_combos),
+                },
+            ],
+        };
+
+        assert_eq!(test_util::round_trip(&mut game_object), game_object);
+    }
+}
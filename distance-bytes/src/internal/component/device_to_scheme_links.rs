@@ -0,0 +1,48 @@
+use crate::internal::{Serializable, Visitor};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// Assigns a `ControlScheme` to each input device.
+#[derive(Debug, Clone, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct DeviceToSchemeLinks {
+    pub links: Vec<DeviceToSchemeLink>,
+}
+
+impl DeviceToSchemeLinks {
+    /// Returns the name of the scheme linked to the given device.
+    pub fn scheme_for(&self, device_name: &str) -> Option<&str> {
+        self.links
+            .iter()
+            .find(|link| link.device_name.as_deref() == Some(device_name))
+            .and_then(|link| link.scheme_name.as_deref())
+    }
+}
+
+impl Serializable for DeviceToSchemeLinks {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        visitor.visit_array("Links", &mut self.links, |visitor, link| {
+            link.accept(visitor, 0)
+        })?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct DeviceToSchemeLink {
+    pub device_name: Option<String>,
+    pub scheme_name: Option<String>,
+}
+
+impl Serializable for DeviceToSchemeLink {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        visitor.visit_string("DeviceName", &mut self.device_name)?;
+        visitor.visit_string("SchemeName", &mut self.scheme_name)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use crate::internal::{Serializable, Visitor};
+use crate::Abilities;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// Enables the flagged abilities, leaving the others as they were.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct EnableAbilitiesTrigger {
+    pub abilities: Abilities,
+    pub bloom_out: bool,
+}
+
+impl EnableAbilitiesTrigger {
+    /// Returns the abilities a car with `current` abilities has after passing through this
+    /// trigger.
+    pub fn apply(&self, current: Abilities) -> Abilities {
+        current.union(self.abilities)
+    }
+}
+
+impl Default for EnableAbilitiesTrigger {
+    fn default() -> Self {
+        EnableAbilitiesTrigger {
+            abilities: Abilities::NONE,
+            bloom_out: true,
+        }
+    }
+}
+
+impl Serializable for EnableAbilitiesTrigger {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        self.abilities.accept(&mut visitor, 0)?;
+        visitor.visit_bool("bloomOut_", &mut self.bloom_out)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use crate::internal::{Serializable, Visitor};
+use crate::Color;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FogSkyboxAmbientChangeTrigger {
+    pub change_fog: bool,
+    pub fog_color: Color,
+    pub fog_density: f32,
+    pub change_skybox: bool,
+    pub skybox_index: i32,
+    pub change_ambient: bool,
+    pub ambient_color: Color,
+    pub transition_time: f32,
+    pub change_fog_height: bool,
+    pub fog_height: f32,
+    pub fog_height_density: f32,
+    pub one_shot: bool,
+}
+
+impl Default for FogSkyboxAmbientChangeTrigger {
+    fn default() -> Self {
+        FogSkyboxAmbientChangeTrigger {
+            change_fog: true,
+            fog_color: Color::new(0.5, 0.5, 0.5, 1.0),
+            fog_density: 0.01,
+            change_skybox: false,
+            skybox_index: 0,
+            change_ambient: false,
+            ambient_color: Color::new(0.2, 0.2, 0.2, 1.0),
+            transition_time: 1.0,
+            change_fog_height: false,
+            fog_height: 0.0,
+            fog_height_density: 1.0,
+            one_shot: false,
+        }
+    }
+}
+
+impl Serializable for FogSkyboxAmbientChangeTrigger {
+    const VERSION: i32 = 2;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, version: i32) -> Result<()> {
+        visitor.visit_bool("changeFog_", &mut self.change_fog)?;
+        visitor.visit_color("fogColor_", &mut self.fog_color)?;
+        visitor.visit_f32("fogDensity_", &mut self.fog_density)?;
+        visitor.visit_bool("changeSkybox_", &mut self.change_skybox)?;
+        visitor.visit_i32("skyboxIndex_", &mut self.skybox_index)?;
+        visitor.visit_bool("changeAmbient_", &mut self.change_ambient)?;
+        visitor.visit_color("ambientColor_", &mut self.ambient_color)?;
+        visitor.visit_f32("transitionTime_", &mut self.transition_time)?;
+        if version >= 1 {
+            visitor.visit_bool("changeFogHeight_", &mut self.change_fog_height)?;
+            visitor.visit_f32("fogHeight_", &mut self.fog_height)?;
+            visitor.visit_f32("fogHeightDensity_", &mut self.fog_height_density)?;
+        }
+        if version >= 2 {
+            visitor.visit_bool("oneShot_", &mut self.one_shot)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use crate::internal::{Serializable, Vector3, Visitor};
+use crate::Enum;
+use anyhow::Result;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct ForceVolume {
+    pub force_direction: Vector3,
+    pub force_type: Enum<ForceVolumeForceType>,
+    pub force_multiplier: f32,
+    pub disable_global_gravity: bool,
+    pub wings_only: bool,
+    pub disable_jet_rotation: bool,
+}
+
+impl Default for ForceVolume {
+    fn default() -> Self {
+        ForceVolume {
+            force_direction: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0,
+            },
+            force_type: Default::default(),
+            force_multiplier: 1.0,
+            disable_global_gravity: false,
+            wings_only: false,
+            disable_jet_rotation: false,
+        }
+    }
+}
+
+impl Serializable for ForceVolume {
+    const VERSION: i32 = 1;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, version: i32) -> Result<()> {
+        visitor.visit_vector_3("forceDirection_", &mut self.force_direction)?;
+        visitor.visit_enum("forceType_", &mut self.force_type)?;
+        visitor.visit_f32("forceMultiplier_", &mut self.force_multiplier)?;
+        visitor.visit_bool("disableGlobalGravity_", &mut self.disable_global_gravity)?;
+        visitor.visit_bool("wingsOnly_", &mut self.wings_only)?;
+        if version >= 1 {
+            visitor.visit_bool("disableJetRotation_", &mut self.disable_jet_rotation)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Hash,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    IntoPrimitive,
+    TryFromPrimitive,
+)]
+#[repr(i32)]
+pub enum ForceVolumeForceType {
+    Wind = 0,
+    Gravity = 1,
+    Vortex = 2,
+}
+
+impl Default for ForceVolumeForceType {
+    fn default() -> Self {
+        ForceVolumeForceType::Wind
+    }
+}
@@ -0,0 +1,32 @@
+use crate::internal::{Serializable, Visitor};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct GravityToggle {
+    pub disable_gravity: bool,
+    pub drag_scale: f32,
+    pub drag_scale_angular: f32,
+}
+
+impl Default for GravityToggle {
+    fn default() -> Self {
+        GravityToggle {
+            disable_gravity: true,
+            drag_scale: 1.0,
+            drag_scale_angular: 1.0,
+        }
+    }
+}
+
+impl Serializable for GravityToggle {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        visitor.visit_bool("disableGravity_", &mut self.disable_gravity)?;
+        visitor.visit_f32("dragScale_", &mut self.drag_scale)?;
+        visitor.visit_f32("dragScaleAngular_", &mut self.drag_scale_angular)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use crate::internal::{Serializable, Visitor};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct InfiniteCooldownTrigger {
+    pub enable_infinite_cooldown: bool,
+    pub one_shot: bool,
+}
+
+impl Default for InfiniteCooldownTrigger {
+    fn default() -> Self {
+        InfiniteCooldownTrigger {
+            enable_infinite_cooldown: true,
+            one_shot: false,
+        }
+    }
+}
+
+impl Serializable for InfiniteCooldownTrigger {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        visitor.visit_bool(
+            "enableInfiniteCooldown_",
+            &mut self.enable_infinite_cooldown,
+        )?;
+        visitor.visit_bool("oneShot_", &mut self.one_shot)?;
+
+        Ok(())
+    }
+}
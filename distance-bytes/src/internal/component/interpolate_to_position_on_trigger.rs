@@ -0,0 +1,54 @@
+use crate::internal::{Serializable, Vector3, Visitor, ZEROS_VECTOR_3};
+use crate::{Animator, AnimatorBase, AnimatorBaseTriggerAction};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct InterpolateToPositionOnTrigger {
+    pub base: AnimatorBase,
+    pub local: bool,
+    pub target_position: Vector3,
+}
+
+impl Default for InterpolateToPositionOnTrigger {
+    fn default() -> Self {
+        InterpolateToPositionOnTrigger {
+            base: AnimatorBase {
+                loop_: false,
+                default_action: AnimatorBaseTriggerAction::None.into(),
+                on_action: AnimatorBaseTriggerAction::Play.into(),
+                ..Default::default()
+            },
+            local: true,
+            target_position: ZEROS_VECTOR_3,
+        }
+    }
+}
+
+impl Animator for InterpolateToPositionOnTrigger {
+    fn animator_base(&self) -> &AnimatorBase {
+        &self.base
+    }
+
+    fn animator_base_mut(&mut self) -> &mut AnimatorBase {
+        &mut self.base
+    }
+}
+
+impl Serializable for InterpolateToPositionOnTrigger {
+    const VERSION: i32 = 2;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, version: i32) -> Result<()> {
+        visitor.visit_bool("local_", &mut self.local)?;
+        visitor.visit_vector_3("targetPosition_", &mut self.target_position)?;
+
+        if version >= 2 {
+            self.base.visit_curve(&mut visitor)?;
+            self.base.visit_trigger(&mut visitor)?;
+        } else {
+            self.base.visit_curve_simple(&mut visitor, version >= 1)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use crate::internal::{Quaternion, Serializable, Visitor, DEFAULT_QUATERNION};
+use crate::{Animator, AnimatorBase, AnimatorBaseTriggerAction};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct InterpolateToRotationOnTrigger {
+    pub base: AnimatorBase,
+    pub local: bool,
+    pub target_rotation: Quaternion,
+}
+
+impl Default for InterpolateToRotationOnTrigger {
+    fn default() -> Self {
+        InterpolateToRotationOnTrigger {
+            base: AnimatorBase {
+                loop_: false,
+                default_action: AnimatorBaseTriggerAction::None.into(),
+                on_action: AnimatorBaseTriggerAction::Play.into(),
+                ..Default::default()
+            },
+            local: true,
+            target_rotation: DEFAULT_QUATERNION,
+        }
+    }
+}
+
+impl Animator for InterpolateToRotationOnTrigger {
+    fn animator_base(&self) -> &AnimatorBase {
+        &self.base
+    }
+
+    fn animator_base_mut(&mut self) -> &mut AnimatorBase {
+        &mut self.base
+    }
+}
+
+impl Serializable for InterpolateToRotationOnTrigger {
+    const VERSION: i32 = 1;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, version: i32) -> Result<()> {
+        visitor.visit_bool("local_", &mut self.local)?;
+        visitor.visit_quaternion("targetRotation_", &mut self.target_rotation)?;
+
+        if version >= 1 {
+            self.base.visit_curve(&mut visitor)?;
+            self.base.visit_trigger(&mut visitor)?;
+        } else {
+            self.base.visit_curve_simple(&mut visitor, true)?;
+        }
+
+        Ok(())
+    }
+}
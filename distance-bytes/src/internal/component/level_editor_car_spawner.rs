@@ -0,0 +1,20 @@
+use crate::internal::{Serializable, Visitor};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Copy, Clone, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize,
+)]
+pub struct LevelEditorCarSpawner {
+    pub spawn_at_camera: bool,
+}
+
+impl Serializable for LevelEditorCarSpawner {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        visitor.visit_bool("spawnAtCamera_", &mut self.spawn_at_camera)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use crate::internal::component::Transform;
+use crate::internal::{Serializable, Visitor};
+use crate::{Component, ComponentData, Enum, GameModeId, GameObject};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// An ordered list of levels to play, each in a given game mode.
+#[derive(Debug, Clone, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct LevelPlaylist {
+    pub name: Option<String>,
+    pub entries: Vec<LevelPlaylistEntry>,
+}
+
+impl LevelPlaylist {
+    pub fn new(name: impl Into<String>) -> Self {
+        LevelPlaylist {
+            name: Some(name.into()),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Creates a playlist that plays every level in `relative_level_paths` in the same mode.
+    pub fn from_levels<I>(
+        name: impl Into<String>,
+        relative_level_paths: I,
+        mode: GameModeId,
+    ) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let mut playlist = LevelPlaylist::new(name);
+        for relative_level_path in relative_level_paths {
+            playlist.push(relative_level_path, mode);
+        }
+
+        playlist
+    }
+
+    /// Appends a level to the end of the playlist.
+    pub fn push(&mut self, relative_level_path: impl Into<String>, mode: GameModeId) {
+        self.entries.push(LevelPlaylistEntry {
+            relative_level_path: Some(relative_level_path.into()),
+            game_mode: mode.into(),
+        });
+    }
+
+    /// Wraps the playlist in a `GameObject` with a default `Transform`, ready to be written with
+    /// [`GameObject::write_to_file`].
+    pub fn into_game_object(self) -> GameObject {
+        GameObject {
+            name: "LevelPlaylist".to_owned(),
+            guid: 0,
+            components: vec![
+                Component {
+                    version: Transform::VERSION,
+                    guid: 0,
+                    data: ComponentData::Transform(Transform::default()),
+                },
+                Component {
+                    version: LevelPlaylist::VERSION,
+                    guid: 0,
+                    data: ComponentData::LevelPlaylist(self),
+                },
+            ],
+        }
+    }
+}
+
+impl Serializable for LevelPlaylist {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        visitor.visit_string("playlistName_", &mut self.name)?;
+        visitor.visit_array("Entries", &mut self.entries, |visitor, entry| {
+            entry.accept(visitor, 0)
+        })?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct LevelPlaylistEntry {
+    pub relative_level_path: Option<String>,
+    pub game_mode: Enum<GameModeId>,
+}
+
+impl Serializable for LevelPlaylistEntry {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        visitor.visit_string("RelativeLevelPath", &mut self.relative_level_path)?;
+        visitor.visit_enum("GameMode", &mut self.game_mode)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internal::test_util;
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let mut playlist = LevelPlaylist::from_levels(
+            "Sprint Favourites",
+            vec![
+                "MyLevels/Broken Symmetry.bytes",
+                "MyLevels/Lost Society.bytes",
+            ],
+            GameModeId::Sprint,
+        );
+        playlist.push("MyLevels/Stunt Playground.bytes", GameModeId::Stunt);
+
+        let mut game_object = playlist.into_game_object();
+        let read = test_util::round_trip(&mut game_object);
+
+        let playlist = match &read.components[..] {
+            [Component {
+                data: ComponentData::Transform(_),
+                ..
+            }, Component {
+                data: ComponentData::LevelPlaylist(playlist),
+                ..
+            }] => playlist,
+            components => panic!("unexpected components: {:?}", components),
+        };
+        assert_eq!(playlist.name.as_deref(), Some("Sprint Favourites"));
+        let entries: Vec<_> = playlist
+            .entries
+            .iter()
+            .map(|entry| {
+                (
+                    entry.relative_level_path.as_deref().unwrap(),
+                    entry.game_mode.variant(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                ("MyLevels/Broken Symmetry.bytes", Some(GameModeId::Sprint)),
+                ("MyLevels/Lost Society.bytes", Some(GameModeId::Sprint)),
+                ("MyLevels/Stunt Playground.bytes", Some(GameModeId::Stunt)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use crate::internal::component::CarReplayData;
+use crate::internal::{Serializable, Visitor};
+use crate::{ComponentData, DistanceDateTime, Enum, GameModeId, GameObject};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// The local best results for a single level and game mode.
+#[derive(Debug, Clone, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct LocalLeaderboard {
+    pub relative_level_path: Option<String>,
+    pub game_mode: Enum<GameModeId>,
+
+    /// Entries ordered by rank, best first
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+impl LocalLeaderboard {
+    pub fn best(&self) -> Option<&LeaderboardEntry> {
+        self.entries.first()
+    }
+
+    /// Returns the entries paired with their 1-based rank.
+    pub fn ranked_entries(&self) -> impl Iterator<Item = (usize, &LeaderboardEntry)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| (i + 1, entry))
+    }
+}
+
+impl Serializable for LocalLeaderboard {
+    const VERSION: i32 = 1;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, version: i32) -> Result<()> {
+        visitor.visit_string("relativeLevelPath_", &mut self.relative_level_path)?;
+        visitor.visit_enum("gameModeID_", &mut self.game_mode)?;
+        visitor.visit_array("Entries", &mut self.entries, |visitor, entry| {
+            entry.accept(&mut *visitor, 0)?;
+            // Entries only gained replay references in version 1 of the leaderboard
+            if version >= 1 {
+                visitor.visit_reference("ReplayRef", &mut entry.replay)?;
+            }
+
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    pub name: Option<String>,
+
+    /// Finish time in milliseconds for timed modes, or points for score-based modes
+    pub value: i32,
+
+    pub date: DistanceDateTime,
+
+    /// Reference to this run's `CarReplayData` component, or zero if no ghost was saved
+    pub replay: u32,
+}
+
+impl LeaderboardEntry {
+    /// Looks up this entry's ghost among the objects under `root`.
+    pub fn replay_data<'a>(&self, root: &'a GameObject) -> Option<&'a CarReplayData> {
+        if self.replay == 0 {
+            return None;
+        }
+
+        root.descendants()
+            .flat_map(|game_object| &game_object.components)
+            .find(|component| component.guid == self.replay)
+            .and_then(|component| match &component.data {
+                ComponentData::CarReplayData(replay_data) => Some(replay_data),
+                _ => None,
+            })
+    }
+}
+
+/// Visits every field except `replay`, which `LocalLeaderboard` visits depending on its version.
+impl Serializable for LeaderboardEntry {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        visitor.visit_string("Name", &mut self.name)?;
+        visitor.visit_i32("Value", &mut self.value)?;
+        visitor.visit_datetime("Date", &mut self.date)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::Transform;
+    use crate::internal::test_util;
+    use crate::Component;
+
+    fn leaderboard(version: i32) -> GameObject {
+        let leaderboard = LocalLeaderboard {
+            relative_level_path: Some("OfficialLevels/Broken Symmetry.bytes".to_owned()),
+            game_mode: GameModeId::Sprint.into(),
+            entries: vec![LeaderboardEntry {
+                name: Some("Player".to_owned()),
+                value: 61234,
+                replay: 42,
+                ..Default::default()
+            }],
+        };
+
+        GameObject {
+            name: "LocalLeaderboard".to_owned(),
+            guid: 0,
+            components: vec![Component {
+                version,
+                guid: 0,
+                data: ComponentData::LocalLeaderboard(leaderboard),
+            }],
+        }
+    }
+
+    fn replay(game_object: &GameObject) -> u32 {
+        match &game_object.components[0].data {
+            ComponentData::LocalLeaderboard(leaderboard) => leaderboard.entries[0].replay,
+            data => panic!("unexpected component: {:?}", data),
+        }
+    }
+
+    #[test]
+    fn test_replay_reference_depends_on_version() {
+        let mut current = leaderboard(LocalLeaderboard::VERSION);
+        assert_eq!(replay(&test_util::round_trip(&mut current)), 42);
+
+        let mut old = leaderboard(0);
+        assert_eq!(replay(&test_util::round_trip(&mut old)), 0);
+    }
+
+    #[test]
+    fn test_replay_data_matches_component_guid() {
+        let mut ghost = test_util::game_object(
+            "Ghost",
+            vec![ComponentData::CarReplayData(CarReplayData::default())],
+        );
+        ghost.guid = 7;
+        ghost.components[0].guid = 42;
+        let root = test_util::game_object(
+            "Root",
+            vec![ComponentData::Transform(Transform {
+                children: vec![ghost],
+                ..Default::default()
+            })],
+        );
+
+        let entry = |replay| LeaderboardEntry {
+            replay,
+            ..Default::default()
+        };
+        assert!(entry(42).replay_data(&root).is_some());
+        assert!(entry(7).replay_data(&root).is_none());
+        assert!(entry(0).replay_data(&root).is_none());
+    }
+}
@@ -0,0 +1,60 @@
+use crate::internal::{Serializable, Visitor};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// Emits bursts of particles from a `ParticleSystem` at a fixed interval.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct ParticleEmitLogic {
+    /// Reference to a `ParticleSystem`
+    pub particle_system: u32,
+
+    pub emit_count: i32,
+    pub interval: f32,
+    pub emit_on_start: bool,
+}
+
+impl ParticleEmitLogic {
+    /// Emission rate, in particles per second, at or above which the emitter is flagged as
+    /// likely to hurt frame rate. The emitted particles are simulated on the CPU by a
+    /// `ParticleSystem`, so this is set far lower than
+    /// [`ParticlesGPU::EXPENSIVE_PARTICLE_COUNT`](crate::component::ParticlesGPU::EXPENSIVE_PARTICLE_COUNT).
+    /// Like that threshold, it's a rule of thumb for triage, not a measured limit.
+    pub const EXPENSIVE_EMISSION_RATE: f32 = 1000.0;
+
+    /// Average particles emitted per second.
+    pub fn emission_rate(&self) -> f32 {
+        if self.interval > 0.0 {
+            self.emit_count.max(0) as f32 / self.interval
+        } else {
+            0.0
+        }
+    }
+
+    pub fn is_expensive(&self) -> bool {
+        self.emission_rate() >= Self::EXPENSIVE_EMISSION_RATE
+    }
+}
+
+impl Default for ParticleEmitLogic {
+    fn default() -> Self {
+        ParticleEmitLogic {
+            particle_system: 0,
+            emit_count: 10,
+            interval: 1.0,
+            emit_on_start: true,
+        }
+    }
+}
+
+impl Serializable for ParticleEmitLogic {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        visitor.visit_reference("particleSystemRef", &mut self.particle_system)?;
+        visitor.visit_i32("emitCount_", &mut self.emit_count)?;
+        visitor.visit_f32("interval_", &mut self.interval)?;
+        visitor.visit_bool("emitOnStart_", &mut self.emit_on_start)?;
+
+        Ok(())
+    }
+}
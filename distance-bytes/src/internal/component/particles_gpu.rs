@@ -0,0 +1,72 @@
+use crate::internal::{Serializable, Vector3, Visitor, ZEROS_VECTOR_3};
+use crate::Color;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// Particles simulated entirely on the GPU, used for dense effects like dust and snow.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct ParticlesGPU {
+    pub particle_count: i32,
+    pub emission_rate: f32,
+    pub lifetime: f32,
+    pub size: f32,
+    pub color: Color,
+    pub velocity: Vector3,
+    pub bounds: Vector3,
+    pub turbulence: f32,
+}
+
+impl ParticlesGPU {
+    /// Live particle count at or above which the effect is flagged as likely to hurt frame rate.
+    /// This is a rule of thumb for triage, not a measured limit.
+    pub const EXPENSIVE_PARTICLE_COUNT: f32 = 100000.0;
+
+    /// The number of particles alive at once after the effect has been emitting for a full
+    /// lifetime, capped by `particle_count`.
+    pub fn steady_state_particles(&self) -> f32 {
+        let emitted = self.emission_rate.max(0.0) * self.lifetime.max(0.0);
+        emitted.min(self.particle_count.max(0) as f32)
+    }
+
+    pub fn is_expensive(&self) -> bool {
+        self.steady_state_particles() >= Self::EXPENSIVE_PARTICLE_COUNT
+    }
+}
+
+impl Default for ParticlesGPU {
+    fn default() -> Self {
+        ParticlesGPU {
+            particle_count: 10000,
+            emission_rate: 1000.0,
+            lifetime: 10.0,
+            size: 0.1,
+            color: Color::new(1.0, 1.0, 1.0, 1.0),
+            velocity: ZEROS_VECTOR_3,
+            bounds: Vector3 {
+                x: 100.0,
+                y: 100.0,
+                z: 100.0,
+            },
+            turbulence: 0.0,
+        }
+    }
+}
+
+impl Serializable for ParticlesGPU {
+    const VERSION: i32 = 1;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, version: i32) -> Result<()> {
+        visitor.visit_i32("particleCount_", &mut self.particle_count)?;
+        visitor.visit_f32("emissionRate_", &mut self.emission_rate)?;
+        visitor.visit_f32("lifetime_", &mut self.lifetime)?;
+        visitor.visit_f32("size_", &mut self.size)?;
+        visitor.visit_color("color_", &mut self.color)?;
+        visitor.visit_vector_3("velocity_", &mut self.velocity)?;
+        visitor.visit_vector_3("bounds_", &mut self.bounds)?;
+        if version >= 1 {
+            visitor.visit_f32("turbulence_", &mut self.turbulence)?;
+        }
+
+        Ok(())
+    }
+}
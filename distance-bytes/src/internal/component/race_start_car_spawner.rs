@@ -0,0 +1,32 @@
+use crate::internal::{Serializable, Visitor};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct RaceStartCarSpawner {
+    pub cars_per_row: i32,
+    pub row_spacing: f32,
+    pub column_spacing: f32,
+}
+
+impl Default for RaceStartCarSpawner {
+    fn default() -> Self {
+        RaceStartCarSpawner {
+            cars_per_row: 4,
+            row_spacing: 8.0,
+            column_spacing: 6.0,
+        }
+    }
+}
+
+impl Serializable for RaceStartCarSpawner {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        visitor.visit_i32("carsPerRow_", &mut self.cars_per_row)?;
+        visitor.visit_f32("rowSpacing_", &mut self.row_spacing)?;
+        visitor.visit_f32("columnSpacing_", &mut self.column_spacing)?;
+
+        Ok(())
+    }
+}
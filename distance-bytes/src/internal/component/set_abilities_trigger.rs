@@ -0,0 +1,34 @@
+use crate::internal::{Serializable, Visitor};
+use crate::Abilities;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// Replaces the car's abilities with exactly the flagged ones.
+#[derive(
+    Debug, Copy, Clone, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize,
+)]
+pub struct SetAbilitiesTrigger {
+    pub abilities: Abilities,
+    pub show_ability_alert: bool,
+}
+
+impl SetAbilitiesTrigger {
+    /// Returns the abilities a car has after passing through this trigger, which don't depend
+    /// on the abilities it had before.
+    pub fn apply(&self) -> Abilities {
+        self.abilities
+    }
+}
+
+impl Serializable for SetAbilitiesTrigger {
+    const VERSION: i32 = 1;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, version: i32) -> Result<()> {
+        self.abilities.accept(&mut visitor, 0)?;
+        if version >= 1 {
+            visitor.visit_bool("showAbilityAlert_", &mut self.show_ability_alert)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use crate::internal::{Serializable, Visitor};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct SphericalGravity {
+    pub inner_radius: f32,
+    pub outer_radius: f32,
+    pub gravity_strength: f32,
+    pub falloff_exponent: f32,
+    pub invert: bool,
+}
+
+impl Default for SphericalGravity {
+    fn default() -> Self {
+        SphericalGravity {
+            inner_radius: 10.0,
+            outer_radius: 50.0,
+            gravity_strength: 25.0,
+            falloff_exponent: 1.0,
+            invert: false,
+        }
+    }
+}
+
+impl Serializable for SphericalGravity {
+    const VERSION: i32 = 1;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, version: i32) -> Result<()> {
+        visitor.visit_f32("innerRadius_", &mut self.inner_radius)?;
+        visitor.visit_f32("outerRadius_", &mut self.outer_radius)?;
+        visitor.visit_f32("gravityStrength_", &mut self.gravity_strength)?;
+        if version >= 1 {
+            visitor.visit_f32("falloffExponent_", &mut self.falloff_exponent)?;
+            visitor.visit_bool("invert_", &mut self.invert)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use crate::internal::{Serializable, Visitor};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// Keyboard shortcuts for the level editor's tools.
+#[derive(Debug, Clone, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct ToolInputCombos {
+    pub combos: Vec<ToolInputCombo>,
+}
+
+impl ToolInputCombos {
+    /// Returns the combo bound to the given tool.
+    pub fn combo(&self, tool_name: &str) -> Option<&ToolInputCombo> {
+        self.combos
+            .iter()
+            .find(|combo| combo.tool_name.as_deref() == Some(tool_name))
+    }
+}
+
+impl Serializable for ToolInputCombos {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        visitor.visit_array("Combos", &mut self.combos, |visitor, combo| {
+            combo.accept(visitor, 0)
+        })?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct ToolInputCombo {
+    pub tool_name: Option<String>,
+
+    /// Names of the inputs that must be held together, e.g. `Keyboard/LeftControl` and
+    /// `Keyboard/D`
+    pub inputs: Vec<Option<String>>,
+}
+
+impl Serializable for ToolInputCombo {
+    const VERSION: i32 = 0;
+
+    fn accept<V: Visitor>(&mut self, mut visitor: V, _version: i32) -> Result<()> {
+        visitor.visit_string("ToolName", &mut self.tool_name)?;
+        visitor.visit_array("Inputs", &mut self.inputs, |visitor, input| {
+            visitor.visit_string("Input", input)
+        })?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use crate::internal::{
-    Quaternion, Serializable, Vector3, VisitDirection, Visitor, DEFAULT_QUATERNION, ONES_VECTOR_3,
-    ZEROS_VECTOR_3,
+    util, Quaternion, Serializable, Vector3, VisitDirection, Visitor, DEFAULT_QUATERNION,
+    ONES_VECTOR_3, ZEROS_VECTOR_3,
 };
 use crate::GameObject;
 use anyhow::Result;
@@ -25,6 +25,61 @@ impl Default for Transform {
     }
 }
 
+/// A position, rotation and scale relative to the level's origin, as produced by composing the
+/// `Transform`s of an object and all its ancestors. See [`GameObject::world_transforms`].
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct WorldTransform {
+    pub position: Vector3,
+    pub rotation: Quaternion,
+    pub scale: Vector3,
+}
+
+impl WorldTransform {
+    pub const IDENTITY: WorldTransform = WorldTransform {
+        position: ZEROS_VECTOR_3,
+        rotation: DEFAULT_QUATERNION,
+        scale: ONES_VECTOR_3,
+    };
+
+    /// Returns the world transform of a child with the given local transform.
+    ///
+    /// Scale is combined per axis, like Unity's `lossyScale`, so the result is approximate for
+    /// rotated children of non-uniformly scaled parents.
+    pub fn then(&self, local: &Transform) -> WorldTransform {
+        WorldTransform {
+            position: self.transform_point(local.position),
+            rotation: util::multiply_quaternions(self.rotation, local.rotation),
+            scale: Vector3 {
+                x: self.scale.x * local.scale.x,
+                y: self.scale.y * local.scale.y,
+                z: self.scale.z * local.scale.z,
+            },
+        }
+    }
+
+    /// Converts a point from this transform's local space to world space.
+    pub fn transform_point(&self, point: Vector3) -> Vector3 {
+        let scaled = Vector3 {
+            x: point.x * self.scale.x,
+            y: point.y * self.scale.y,
+            z: point.z * self.scale.z,
+        };
+        let rotated = util::rotate_vector(self.rotation, scaled);
+
+        Vector3 {
+            x: self.position.x + rotated.x,
+            y: self.position.y + rotated.y,
+            z: self.position.z + rotated.z,
+        }
+    }
+}
+
+impl Default for WorldTransform {
+    fn default() -> Self {
+        WorldTransform::IDENTITY
+    }
+}
+
 impl Serializable for Transform {
     const VERSION: i32 = 0;
 
@@ -83,3 +138,69 @@ impl Serializable for Transform {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internal::test_util;
+    use crate::internal::util::ApproximatelyEquals;
+    use crate::ComponentData;
+
+    fn assert_approx_eq(actual: Vector3, expected: [f32; 3]) {
+        assert!(
+            actual.approximately_equals(&Vector3::from(expected)),
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_world_transforms() {
+        let half_sqrt_2 = std::f32::consts::FRAC_1_SQRT_2;
+        let grandchild = test_util::game_object(
+            "Grandchild",
+            vec![ComponentData::Transform(Transform {
+                position: Vector3::from([0.0, 1.0, 0.0]),
+                ..Default::default()
+            })],
+        );
+        let child = test_util::game_object(
+            "Child",
+            vec![ComponentData::Transform(Transform {
+                position: Vector3::from([0.0, 0.0, 1.0]),
+                children: vec![grandchild],
+                ..Default::default()
+            })],
+        );
+        let root = test_util::game_object(
+            "Root",
+            vec![ComponentData::Transform(Transform {
+                position: Vector3::from([10.0, 0.0, 0.0]),
+                // 90 degrees about the Y axis, taking +Z to +X
+                rotation: Quaternion::from([0.0, half_sqrt_2, 0.0, half_sqrt_2]),
+                scale: Vector3::from([2.0, 2.0, 2.0]),
+                children: vec![child],
+            })],
+        );
+
+        let world_transforms: Vec<_> = root.world_transforms().collect();
+        let names: Vec<_> = world_transforms
+            .iter()
+            .map(|(game_object, _)| game_object.name.as_str())
+            .collect();
+        assert_eq!(names, ["Root", "Child", "Grandchild"]);
+
+        assert_approx_eq(world_transforms[0].1.position, [10.0, 0.0, 0.0]);
+        assert_approx_eq(world_transforms[1].1.position, [12.0, 0.0, 0.0]);
+        assert_approx_eq(world_transforms[2].1.position, [12.0, 2.0, 0.0]);
+        assert_approx_eq(world_transforms[2].1.scale, [2.0, 2.0, 2.0]);
+        assert_approx_eq(
+            util::rotate_vector(
+                world_transforms[2].1.rotation,
+                Vector3::from([0.0, 0.0, 1.0]),
+            ),
+            [1.0, 0.0, 0.0],
+        );
+    }
+}
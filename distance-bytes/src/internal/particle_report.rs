@@ -0,0 +1,110 @@
+use crate::{ComponentData, GameObject};
+
+/// The particle effects of a level, for finding the ones likely to hurt frame rate.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParticleReport<'a> {
+    /// One entry per `ParticleEmitLogic` or `ParticlesGPU` component, in depth-first order
+    pub effects: Vec<ParticleEffect<'a>>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ParticleEffect<'a> {
+    pub game_object: &'a GameObject,
+
+    /// Average particles emitted per second
+    pub emission_rate: f32,
+
+    /// Particles alive at once, if it can be estimated from the component alone. A
+    /// `ParticleEmitLogic`'s particles live as long as its `ParticleSystem` says, which isn't
+    /// decoded.
+    pub live_particles: Option<f32>,
+
+    /// Whether the component's `is_expensive` flags it
+    pub is_expensive: bool,
+}
+
+impl<'a> ParticleReport<'a> {
+    pub fn from_level(level: &'a GameObject) -> Self {
+        let effects = level
+            .descendants()
+            .flat_map(|game_object| {
+                game_object
+                    .components
+                    .iter()
+                    .filter_map(move |component| match &component.data {
+                        ComponentData::ParticleEmitLogic(emit_logic) => Some(ParticleEffect {
+                            game_object,
+                            emission_rate: emit_logic.emission_rate(),
+                            live_particles: None,
+                            is_expensive: emit_logic.is_expensive(),
+                        }),
+                        ComponentData::ParticlesGPU(particles) => Some(ParticleEffect {
+                            game_object,
+                            emission_rate: particles.emission_rate.max(0.0),
+                            live_particles: Some(particles.steady_state_particles()),
+                            is_expensive: particles.is_expensive(),
+                        }),
+                        _ => None,
+                    })
+            })
+            .collect();
+
+        ParticleReport { effects }
+    }
+
+    pub fn total_emission_rate(&self) -> f32 {
+        self.effects.iter().map(|effect| effect.emission_rate).sum()
+    }
+
+    pub fn expensive_effects(&self) -> impl Iterator<Item = &ParticleEffect<'a>> {
+        self.effects.iter().filter(|effect| effect.is_expensive)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::{ParticleEmitLogic, ParticlesGPU, Transform};
+    use crate::internal::test_util;
+
+    #[test]
+    fn test_from_level() {
+        let sparks = test_util::game_object(
+            "Sparks",
+            vec![ComponentData::ParticleEmitLogic(ParticleEmitLogic {
+                emit_count: 50,
+                interval: 0.5,
+                ..Default::default()
+            })],
+        );
+        let snow = test_util::game_object(
+            "Snow",
+            vec![ComponentData::ParticlesGPU(ParticlesGPU {
+                particle_count: 200000,
+                emission_rate: 50000.0,
+                lifetime: 4.0,
+                ..Default::default()
+            })],
+        );
+        let level = test_util::game_object(
+            "Level",
+            vec![ComponentData::Transform(Transform {
+                children: vec![sparks, snow],
+                ..Default::default()
+            })],
+        );
+
+        let report = ParticleReport::from_level(&level);
+        assert_eq!(report.effects.len(), 2);
+        assert_eq!(report.effects[0].emission_rate, 100.0);
+        assert_eq!(report.effects[0].live_particles, None);
+        assert_eq!(report.effects[1].live_particles, Some(200000.0));
+        assert_eq!(report.total_emission_rate(), 50100.0);
+
+        let expensive: Vec<_> = report
+            .expensive_effects()
+            .map(|effect| effect.game_object.name.as_str())
+            .collect();
+        assert_eq!(expensive, ["Snow"]);
+    }
+}
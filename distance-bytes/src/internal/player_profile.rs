@@ -0,0 +1,54 @@
+use crate::component::{ProfileProgress, ProfileStats};
+use crate::{ComponentData, GameObject, RawComponentData};
+use anyhow::{format_err, Result};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Seek};
+use std::path::Path;
+
+/// The contents of a profile file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlayerProfile {
+    /// The `Profile` component, which isn't decoded yet
+    pub profile: RawComponentData,
+
+    pub progress: ProfileProgress,
+    pub stats: ProfileStats,
+}
+
+impl PlayerProfile {
+    /// Extracts the `Profile`, `ProfileProgress` and `ProfileStats` components from a profile
+    /// file's object hierarchy, failing if any of them is missing.
+    pub fn from_game_object(game_object: &GameObject) -> Result<PlayerProfile> {
+        let mut profile = None;
+        let mut progress = None;
+        let mut stats = None;
+        for component in game_object
+            .descendants()
+            .flat_map(|game_object| &game_object.components)
+        {
+            match &component.data {
+                ComponentData::Profile(x) if profile.is_none() => profile = Some(x.clone()),
+                ComponentData::ProfileProgress(x) if progress.is_none() => {
+                    progress = Some(x.clone())
+                }
+                ComponentData::ProfileStats(x) if stats.is_none() => stats = Some((**x).clone()),
+                _ => {}
+            }
+        }
+
+        Ok(PlayerProfile {
+            profile: profile.ok_or_else(|| format_err!("profile has no Profile component"))?,
+            progress: progress
+                .ok_or_else(|| format_err!("profile has no ProfileProgress component"))?,
+            stats: stats.ok_or_else(|| format_err!("profile has no ProfileStats component"))?,
+        })
+    }
+
+    pub fn read_from_reader(reader: impl Read + Seek) -> Result<PlayerProfile> {
+        PlayerProfile::from_game_object(&GameObject::read_from_reader(reader)?)
+    }
+
+    pub fn read_from_file(path: impl AsRef<Path>) -> Result<PlayerProfile> {
+        PlayerProfile::from_game_object(&GameObject::read_from_file(path)?)
+    }
+}
@@ -0,0 +1,218 @@
+use crate::internal::component::WorldTransform;
+use crate::{ComponentData, GameModeId, GameObject, LevelInfo, Vector3};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+/// The family of car spawner components. The game picks which family to spawn cars from based
+/// on the game mode being played.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub enum SpawnerKind {
+    /// `CarSpawner`, a plain start point that can stand in for any other kind
+    Generic,
+
+    /// `RaceStartCarSpawner`, the starting grid of race modes
+    RaceStart,
+
+    /// `ArenaCarSpawner`, the spawn points of arena modes
+    Arena,
+
+    /// `LevelEditorCarSpawner`, used when test-driving a level in the editor
+    LevelEditor,
+}
+
+impl SpawnerKind {
+    pub fn from_component_data(component_data: &ComponentData) -> Option<SpawnerKind> {
+        match component_data {
+            ComponentData::CarSpawner(_) => Some(SpawnerKind::Generic),
+            ComponentData::RaceStartCarSpawner(_) => Some(SpawnerKind::RaceStart),
+            ComponentData::ArenaCarSpawner(_) => Some(SpawnerKind::Arena),
+            ComponentData::LevelEditorCarSpawner(_) => Some(SpawnerKind::LevelEditor),
+            _ => None,
+        }
+    }
+
+    /// Returns the kind of spawner cars start from in `mode`, or `None` for modes that don't
+    /// spawn a car from the level, like the main menu.
+    ///
+    /// Stunt, Soccer, Reverse Tag and Free Roam are played in arenas; every other playable mode
+    /// is a race from a starting grid.
+    pub fn for_mode(mode: GameModeId) -> Option<SpawnerKind> {
+        match mode {
+            GameModeId::Sprint
+            | GameModeId::CoopSprint
+            | GameModeId::Challenge
+            | GameModeId::Adventure
+            | GameModeId::SpeedAndStyle
+            | GameModeId::Trackmogrify
+            | GameModeId::LostToEchoes
+            | GameModeId::Nexus
+            | GameModeId::TheOtherSide => Some(SpawnerKind::RaceStart),
+            GameModeId::Stunt
+            | GameModeId::Soccer
+            | GameModeId::FreeRoam
+            | GameModeId::ReverseTag => Some(SpawnerKind::Arena),
+            GameModeId::LevelEditorPlay => Some(SpawnerKind::LevelEditor),
+            GameModeId::None | GameModeId::Demo | GameModeId::MainMenu => None,
+        }
+    }
+
+    /// Whether a spawner of this kind can start a car in a mode that needs `required`.
+    pub fn can_serve(self, required: SpawnerKind) -> bool {
+        self == required || self == SpawnerKind::Generic
+    }
+}
+
+/// A car spawner in a level, along with where it is.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpawnPoint<'a> {
+    pub game_object: &'a GameObject,
+    pub kind: SpawnerKind,
+    pub world_transform: WorldTransform,
+}
+
+impl<'a> SpawnPoint<'a> {
+    /// Returns every car spawner under `level`, in depth-first order.
+    pub fn find_all(level: &'a GameObject) -> Vec<SpawnPoint<'a>> {
+        level
+            .world_transforms()
+            .flat_map(|(game_object, world_transform)| {
+                game_object.components.iter().filter_map(move |component| {
+                    SpawnerKind::from_component_data(&component.data).map(|kind| SpawnPoint {
+                        game_object,
+                        kind,
+                        world_transform,
+                    })
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the modes enabled in `level_info` that have no spawner in `level` to start a car
+    /// from.
+    pub fn modes_without_spawner(level_info: &LevelInfo, level: &GameObject) -> Vec<GameModeId> {
+        let spawn_points = SpawnPoint::find_all(level);
+        let mut modes: Vec<GameModeId> = level_info
+            .modes
+            .iter()
+            .flatten()
+            .filter(|&(_, &enabled)| enabled)
+            .filter_map(|(&mode_id, _)| GameModeId::try_from(mode_id).ok())
+            .filter(|&mode| match SpawnerKind::for_mode(mode) {
+                Some(required) => !spawn_points
+                    .iter()
+                    .any(|spawn_point| spawn_point.kind.can_serve(required)),
+                None => false,
+            })
+            .collect();
+        modes.sort();
+
+        modes
+    }
+
+    /// Returns the straight-line distance from this spawner to the nearest object with a
+    /// `RaceEndLogic` component in `level`, or `None` if the level has no finish line. This is
+    /// a lower bound on the length of the track, not the length of the racing line.
+    pub fn distance_to_finish(&self, level: &GameObject) -> Option<f32> {
+        let start = self.world_transform.position;
+        level
+            .world_transforms()
+            .filter(|(game_object, _)| {
+                game_object
+                    .components
+                    .iter()
+                    .any(|component| matches!(component.data, ComponentData::RaceEndLogic(_)))
+            })
+            .map(|(_, world_transform)| distance(start, world_transform.position))
+            .min_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+    }
+}
+
+fn distance(a: Vector3, b: Vector3) -> f32 {
+    let (x, y, z) = (b.x - a.x, b.y - a.y, b.z - a.z);
+    (x * x + y * y + z * z).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::{CarSpawner, RaceStartCarSpawner, Transform};
+    use crate::internal::test_util;
+    use crate::RawComponentData;
+    use std::collections::HashMap;
+
+    fn placed(name: &str, position: [f32; 3], data: Option<ComponentData>) -> GameObject {
+        let transform = ComponentData::Transform(Transform {
+            position: Vector3::from(position),
+            ..Default::default()
+        });
+        test_util::game_object(name, std::iter::once(transform).chain(data))
+    }
+
+    fn level(extra_children: Vec<GameObject>) -> GameObject {
+        let start = placed(
+            "RaceStart",
+            [0.0, 0.0, 10.0],
+            Some(ComponentData::RaceStartCarSpawner(
+                RaceStartCarSpawner::default(),
+            )),
+        );
+        let near_finish = placed(
+            "EmpireEndZone",
+            [0.0, 0.0, 110.0],
+            Some(ComponentData::RaceEndLogic(RawComponentData::default())),
+        );
+        let far_finish = placed(
+            "EmpireEndZone",
+            [0.0, 0.0, 510.0],
+            Some(ComponentData::RaceEndLogic(RawComponentData::default())),
+        );
+        test_util::game_object(
+            "Level",
+            vec![ComponentData::Transform(Transform {
+                children: [vec![start, far_finish, near_finish], extra_children].concat(),
+                ..Default::default()
+            })],
+        )
+    }
+
+    #[test]
+    fn test_find_all_and_distance_to_finish() {
+        let level = level(Vec::new());
+        let spawn_points = SpawnPoint::find_all(&level);
+        assert_eq!(spawn_points.len(), 1);
+        assert_eq!(spawn_points[0].kind, SpawnerKind::RaceStart);
+        assert_eq!(spawn_points[0].game_object.name, "RaceStart");
+        assert_eq!(spawn_points[0].distance_to_finish(&level), Some(100.0));
+    }
+
+    #[test]
+    fn test_modes_without_spawner() {
+        let modes: HashMap<i32, bool> = vec![
+            (GameModeId::Sprint.into(), true),
+            (GameModeId::ReverseTag.into(), true),
+            (GameModeId::Stunt.into(), true),
+            (GameModeId::Soccer.into(), false),
+            (GameModeId::MainMenu.into(), true),
+        ]
+        .into_iter()
+        .collect();
+        let level_info = LevelInfo {
+            modes: Some(modes),
+            ..Default::default()
+        };
+
+        let level_without_arena_spawner = level(Vec::new());
+        assert_eq!(
+            SpawnPoint::modes_without_spawner(&level_info, &level_without_arena_spawner),
+            vec![GameModeId::Stunt, GameModeId::ReverseTag]
+        );
+
+        let level = level(vec![placed(
+            "CarSpawner",
+            [0.0, 0.0, 0.0],
+            Some(ComponentData::CarSpawner(CarSpawner::default())),
+        )]);
+        assert!(SpawnPoint::modes_without_spawner(&level_info, &level).is_empty());
+    }
+}
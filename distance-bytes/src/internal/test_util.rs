@@ -1,3 +1,34 @@
+use crate::{Component, ComponentData, GameObject};
+use std::io::Cursor;
+
 pub(crate) fn hex_dump<T: AsRef<[u8]>>(source: &T) -> String {
     pretty_hex::pretty_hex(&source.as_ref())
 }
+
+/// Writes `game_object` to bytes and reads it back.
+pub(crate) fn round_trip(game_object: &mut GameObject) -> GameObject {
+    let mut buf = Cursor::new(Vec::new());
+    game_object.write_to_writer(&mut buf).unwrap();
+    buf.set_position(0);
+
+    GameObject::read_from_reader(buf).unwrap()
+}
+
+/// Builds a `GameObject` holding one version 0 component for each of `components`.
+pub(crate) fn game_object(
+    name: &str,
+    components: impl IntoIterator<Item = ComponentData>,
+) -> GameObject {
+    GameObject {
+        name: name.to_owned(),
+        guid: 0,
+        components: components
+            .into_iter()
+            .map(|data| Component {
+                version: 0,
+                guid: 0,
+                data,
+            })
+            .collect(),
+    }
+}
@@ -29,6 +29,45 @@ impl ApproximatelyEquals for Quaternion {
     }
 }
 
+fn cross(a: Vector3, b: Vector3) -> Vector3 {
+    Vector3 {
+        x: a.y * b.z - a.z * b.y,
+        y: a.z * b.x - a.x * b.z,
+        z: a.x * b.y - a.y * b.x,
+    }
+}
+
+pub(crate) fn rotate_vector(rotation: Quaternion, v: Vector3) -> Vector3 {
+    let t = cross(rotation.v, v);
+    let t = Vector3 {
+        x: 2.0 * t.x,
+        y: 2.0 * t.y,
+        z: 2.0 * t.z,
+    };
+    let u = cross(rotation.v, t);
+
+    Vector3 {
+        x: v.x + rotation.s * t.x + u.x,
+        y: v.y + rotation.s * t.y + u.y,
+        z: v.z + rotation.s * t.z + u.z,
+    }
+}
+
+/// Returns the rotation equivalent to applying `b`, then `a`.
+pub(crate) fn multiply_quaternions(a: Quaternion, b: Quaternion) -> Quaternion {
+    let dot = a.v.x * b.v.x + a.v.y * b.v.y + a.v.z * b.v.z;
+    let cross = cross(a.v, b.v);
+
+    Quaternion {
+        v: Vector3 {
+            x: a.s * b.v.x + b.s * a.v.x + cross.x,
+            y: a.s * b.v.y + b.s * a.v.y + cross.y,
+            z: a.s * b.v.z + b.s * a.v.z + cross.z,
+        },
+        s: a.s * b.s - dot,
+    }
+}
+
 pub(crate) fn scope_mark_string(scope_mark: i32) -> &'static str {
     match scope_mark {
         11111111 => "Array",
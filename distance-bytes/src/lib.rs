@@ -6,16 +6,21 @@
     unused_qualifications
 )]
 
+pub use crate::internal::abilities::*;
 pub use crate::internal::animator_base::*;
+pub use crate::internal::binding_set::*;
 pub use crate::internal::car_colors::*;
 pub use crate::internal::car_data::*;
 pub use crate::internal::component::{Component, ComponentData, RawComponentData};
 pub use crate::internal::level_info::*;
+pub use crate::internal::particle_report::*;
+pub use crate::internal::player_profile::*;
 pub use crate::internal::player_stats::*;
+pub use crate::internal::spawn_points::*;
 pub use crate::internal::{
-    Color, DistanceDateTime, Enum, GameObject, LevelDifficulty, LevelType, MaterialColorInfo,
-    MaterialInfo, MedalStatus, MusicCueId, Quaternion, Vector3, DEFAULT_QUATERNION, ONES_VECTOR_3,
-    ZEROS_VECTOR_3,
+    Color, Descendants, DistanceDateTime, Enum, GameModeId, GameObject, LevelDifficulty, LevelType,
+    MaterialColorInfo, MaterialInfo, MedalStatus, MusicCueId, Quaternion, Vector3, WorldTransforms,
+    DEFAULT_QUATERNION, ONES_VECTOR_3, ZEROS_VECTOR_3,
 };
 
 pub mod component;